@@ -0,0 +1,166 @@
+//! Generates `src/tzdb/data.rs` from the system time zone database.
+//!
+//! ```sh
+//! cargo run --release --example gen_tzdb > src/tzdb/data.rs
+//! ```
+//!
+//! The zone names are read from `tzdata.zi` and the rule of each zone from the
+//! footer of its TZif file, in the directory given by `TZDIR` or
+//! `/usr/share/zoneinfo`.
+//!
+//! Zone names or regions given as arguments limit the table to those zones,
+//! e.g. `cargo run --example gen_tzdb -- Europe America/New_York`. `UTC` and
+//! the `Etc/*` zones are always included.
+//!
+//! Each zone is stored with the time from which its footer rule gives the same
+//! local time types as its transitions, checked hourly until the year 2100.
+//! Zones with transitions after the time of generation that their footer rule
+//! does not describe, such as the yearly Ramadan change of `Africa/Casablanca`,
+//! would get wrong offsets from the rule alone and are left out.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use rfc3339::{PosixTz, Tzif};
+
+const REGIONS: [&str; 10] = [
+    "Africa",
+    "America",
+    "Antarctica",
+    "Arctic",
+    "Asia",
+    "Atlantic",
+    "Australia",
+    "Europe",
+    "Indian",
+    "Pacific",
+];
+
+/// Returns the earliest time from which the rule gives the same local time
+/// types as the TZif file until the year 2100, or `None` if it does not from
+/// `now` on. A rule matching back to 1800, before any recorded transition,
+/// applies at all times and gives `i64::MIN`.
+fn rule_start(zone: &Tzif<Vec<u8>>, rule: &PosixTz<'_>, now: i64) -> Option<i64> {
+    const FIRST: i64 = -5364662400;
+    const END: i64 = 4102444800;
+    const STEP: i64 = 3600;
+    let matches = |seconds: i64| {
+        let (expected, actual) = (zone.local_time_type(seconds), rule.local_time_type(seconds));
+        expected.offset_seconds() == actual.offset_seconds()
+            && expected.abbreviation() == actual.abbreviation()
+    };
+
+    if !(now..END).step_by(STEP as usize).all(matches) {
+        return None;
+    }
+
+    let mut start = now;
+    while start > FIRST {
+        let earlier = start - STEP;
+        if !matches(earlier) {
+            // The types change once within the hour, find the exact second.
+            let (mut low, mut high) = (earlier, start);
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                if matches(mid) {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            return Some(high);
+        }
+        start = earlier;
+    }
+    Some(i64::MIN)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let selected: Vec<String> = std::env::args().skip(1).collect();
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)?
+        .as_secs() as i64;
+
+    let dir = std::env::var_os("TZDIR").unwrap_or_else(|| "/usr/share/zoneinfo".into());
+    let zi = std::fs::read_to_string(PathBuf::from(dir).join("tzdata.zi"))?;
+    let version = zi
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("# version "))
+        .ok_or("missing tzdata version")?;
+
+    let mut regions: BTreeMap<&str, Vec<(&str, i64, String)>> = BTreeMap::new();
+    regions.insert("Etc", Vec::new());
+    for region in REGIONS {
+        regions.insert(region, Vec::new());
+    }
+
+    let mut excluded = Vec::new();
+    for line in zi.lines() {
+        let fields: Vec<_> = line.split_whitespace().collect();
+        let name = match fields[..] {
+            ["Z", name, ..] | ["L", _, name] => name,
+            _ => continue,
+        };
+        let region = match name.split_once('/') {
+            Some((region, _)) if REGIONS.contains(&region) => region,
+            _ if name.starts_with("Etc/") || name == "UTC" => "Etc",
+            _ => continue,
+        };
+        let is_selected = |entry: &String| {
+            name == entry
+                || name
+                    .strip_prefix(entry.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        };
+        if region != "Etc" && !selected.is_empty() && !selected.iter().any(is_selected) {
+            continue;
+        }
+
+        let zone = Tzif::load(name)?;
+        let rule = zone.rule().ok_or_else(|| format!("{} has no rule", name))?;
+        let Some(since) = rule_start(&zone, &rule, now) else {
+            excluded.push(name);
+            continue;
+        };
+        regions
+            .get_mut(region)
+            .expect("all regions are listed")
+            .push((name, since, zone.footer().to_owned()));
+    }
+    excluded.sort();
+
+    println!("//! Generated by `cargo run --release --example gen_tzdb`, do not edit.");
+    println!();
+    println!("/// The version of the tz database the zones were generated from.");
+    println!("pub(super) const VERSION: &str = {:?};", version);
+    if !excluded.is_empty() {
+        println!();
+        println!("// Left out, as their rules do not describe their future transitions:");
+        for name in &excluded {
+            println!("// {}", name);
+        }
+    }
+    for (region, mut zones) in regions {
+        zones.sort();
+        println!();
+        if region != "Etc" {
+            println!("#[cfg(feature = \"tzdb-{}\")]", region.to_lowercase());
+        }
+        println!(
+            "pub(super) const {}: &[(&str, i64, &str)] = &[",
+            region.to_uppercase()
+        );
+        for (name, since, rule) in zones {
+            match since {
+                i64::MIN => println!("    ({:?}, i64::MIN, {:?}),", name, rule),
+                _ => println!("    ({:?}, {}, {:?}),", name, since, rule),
+            }
+        }
+        println!("];");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Writing timestamps into caller provided byte buffers.
+
+use core::fmt;
+
+/// A [`fmt::Write`] implementation over a fixed byte slice that fails once the
+/// slice is full.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns the written part of the buffer.
+    pub(crate) fn into_str(self) -> &'a str {
+        let buf: &'a [u8] = self.buf;
+        core::str::from_utf8(&buf[..self.len]).expect("only whole strings are written")
+    }
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Implements the string traits of a type storing a string inline, which
+/// must have an `as_str(&self) -> &str` method.
+macro_rules! impl_inline_str {
+    ($type:ty) => {
+        impl core::ops::Deref for $type {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl AsRef<str> for $type {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl core::fmt::Display for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+
+        impl core::fmt::Debug for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        impl PartialEq<str> for $type {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $type {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl From<$type> for alloc::string::String {
+            fn from(value: $type) -> Self {
+                value.as_str().into()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_inline_str;
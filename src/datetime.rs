@@ -0,0 +1,335 @@
+//! Broken down calendar date and time of day.
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::buffer::SliceWriter;
+use crate::parse::parse_datetime;
+use crate::{
+    rdn_to_ymd, ymd_to_rdn, Error, ParseError, Precision, Timestamp, UtcOffset, MAX_UNIX_SECONDS,
+    MIN_UNIX_SECONDS, SECONDS_PER_DAY, UNIX_EPOCH,
+};
+
+/// A calendar date and time of day at a fixed offset from UTC.
+///
+/// The fields are the local wall clock time at [`DateTime::offset`], so the
+/// same instant at different offsets compares unequal.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::DateTime;
+///
+/// let datetime = DateTime::from_unix(1445470140, 0).unwrap();
+/// assert_eq!(datetime.year(), 2015);
+/// assert_eq!(datetime.month(), 10);
+/// assert_eq!(datetime.day(), 21);
+/// assert_eq!(datetime.hour(), 23);
+/// assert_eq!(datetime.to_string(), "2015-10-21T23:29:00.000000Z");
+/// assert_eq!(datetime.to_unix(), (1445470140, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    pub(crate) year: u16,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) nanosecond: u32,
+    pub(crate) offset: UtcOffset,
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from a Unix timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more
+    /// and [`Error::YearOutOfRange`] if `seconds` is outside of
+    /// [`MIN_UNIX_SECONDS`] to [`MAX_UNIX_SECONDS`].
+    pub fn from_unix(seconds: i64, nanos: u32) -> Result<DateTime, Error> {
+        DateTime::from_unix_offset(seconds, nanos, UtcOffset::UTC)
+    }
+
+    /// Creates a date and time in local time at the given UTC offset from a
+    /// Unix timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more
+    /// and [`Error::YearOutOfRange`] if the local time falls outside of the
+    /// years 0001 to 9999.
+    pub fn from_unix_offset(
+        seconds: i64,
+        nanos: u32,
+        offset: UtcOffset,
+    ) -> Result<DateTime, Error> {
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidSubsecond);
+        }
+
+        let local = seconds
+            .checked_add(offset.seconds() as i64)
+            .ok_or(Error::Overflow)?;
+        if !(MIN_UNIX_SECONDS..=MAX_UNIX_SECONDS).contains(&local) {
+            return Err(Error::YearOutOfRange);
+        }
+
+        Ok(DateTime::from_rd_seconds(
+            (local + UNIX_EPOCH as i64) as u64,
+            nanos,
+            offset,
+        ))
+    }
+
+    /// Breaks down a number of seconds since the start of Rata Die day zero,
+    /// already shifted to local time by `offset`, the year must be at most
+    /// 9999.
+    pub(crate) fn from_rd_seconds(rd_seconds: u64, nanosecond: u32, offset: UtcOffset) -> Self {
+        let (year, month, day) = rdn_to_ymd(rd_seconds / SECONDS_PER_DAY);
+        debug_assert!(year <= 9999, "years are in range");
+        let sec = rd_seconds % SECONDS_PER_DAY;
+
+        DateTime {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+            hour: (sec / 3600) as u8,
+            minute: (sec % 3600 / 60) as u8,
+            second: (sec % 60) as u8,
+            nanosecond,
+            offset,
+        }
+    }
+
+    /// Returns the year, from 1 to 9999.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59, or 60 for a parsed leap second.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fractional second in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the offset from UTC of the local time.
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Returns the day of the week, 0 for Sunday to 6 for Saturday.
+    pub(crate) fn weekday(&self) -> usize {
+        // Rata Die day 1 is a Monday.
+        (ymd_to_rdn(self.year as u32, self.month as u32, self.day as u32) % 7) as usize
+    }
+
+    /// Converts back into Unix seconds and nanoseconds.
+    ///
+    /// A leap second is folded into the following second.
+    pub fn to_unix(&self) -> (i64, u32) {
+        let rd_seconds = ymd_to_rdn(self.year as u32, self.month as u32, self.day as u32)
+            * SECONDS_PER_DAY
+            + self.hour as u64 * 3600
+            + self.minute as u64 * 60
+            + self.second as u64;
+
+        let seconds = rd_seconds as i64 - UNIX_EPOCH as i64 - self.offset.seconds() as i64;
+        (seconds, self.nanosecond)
+    }
+
+    /// Converts to the same instant in local time at a different UTC offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the local time falls outside of the
+    /// years 0001 to 9999.
+    pub fn to_offset(&self, offset: UtcOffset) -> Result<DateTime, Error> {
+        let (seconds, nanos) = self.to_unix();
+        DateTime::from_unix_offset(seconds, nanos, offset)
+    }
+
+    /// Formats as an RFC3339 timestamp with the given fractional precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrecision`] if a fixed precision is not between
+    /// 1 and 9 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{DateTime, Precision};
+    ///
+    /// let datetime = DateTime::from_unix(1445470140, 120_000_000).unwrap();
+    /// let timestamp = datetime.format(Precision::Auto).unwrap();
+    /// assert_eq!(timestamp, "2015-10-21T23:29:00.12Z");
+    /// ```
+    pub fn format(&self, precision: Precision) -> Result<Timestamp, Error> {
+        precision.validate()?;
+
+        Ok(Timestamp::from_datetime(self, precision))
+    }
+
+    /// Formats as an RFC3339 timestamp into a caller provided buffer, returning
+    /// the written part of the buffer.
+    ///
+    /// A buffer of [`Timestamp::MAX_LEN`] bytes is large enough for any
+    /// timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrecision`] if a fixed precision is not between
+    /// 1 and 9 digits and [`Error::BufferTooSmall`] if the timestamp does not
+    /// fit into `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{DateTime, Precision};
+    ///
+    /// let datetime = DateTime::from_unix(1445470140, 0).unwrap();
+    /// let mut buf = [0u8; 32];
+    /// let timestamp = datetime.format_into(Precision::Seconds, &mut buf).unwrap();
+    /// assert_eq!(timestamp, "2015-10-21T23:29:00Z");
+    /// ```
+    pub fn format_into<'a>(
+        &self,
+        precision: Precision,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, Error> {
+        precision.validate()?;
+
+        let mut writer = SliceWriter::new(buf);
+        self.write(&mut writer, precision)
+            .map_err(|_| Error::BufferTooSmall)?;
+        Ok(writer.into_str())
+    }
+
+    /// Writes as an RFC3339 timestamp, the precision must be valid.
+    pub(crate) fn write<W: Write>(&self, w: &mut W, precision: Precision) -> fmt::Result {
+        write!(
+            w,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        precision.write_fraction(w, self.nanosecond)?;
+        write!(w, "{}", self.offset)
+    }
+}
+
+/// Formats as an RFC3339 timestamp, with six fractional digits unless a
+/// precision is given in the format string, e.g. `{:.3}` for milliseconds or
+/// `{:.0}` for none. Precisions above nine are treated as nine.
+///
+/// This allows writing timestamps directly into any [`fmt::Write`] sink.
+///
+/// ```rust
+/// use rfc3339::DateTime;
+///
+/// let datetime = DateTime::from_unix(1445470140, 123456789).unwrap();
+/// assert_eq!(format!("{}", datetime), "2015-10-21T23:29:00.123456Z");
+/// assert_eq!(format!("{:.3}", datetime), "2015-10-21T23:29:00.123Z");
+/// assert_eq!(format!("{:.0}", datetime), "2015-10-21T23:29:00Z");
+/// ```
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = match f.precision() {
+            None => Precision::MICROS,
+            Some(0) => Precision::Seconds,
+            Some(digits) => Precision::Digits(digits.min(9) as u8),
+        };
+
+        self.write(f, precision)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = ParseError;
+
+    /// Parses an RFC3339 timestamp, keeping the local time and offset.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_datetime(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_unix_offset() {
+        let offset = UtcOffset::from_minutes(-7 * 60).unwrap();
+        let datetime = DateTime::from_unix_offset(1445470140, 5, offset).unwrap();
+
+        assert_eq!(
+            (datetime.year(), datetime.month(), datetime.day()),
+            (2015, 10, 21)
+        );
+        assert_eq!(
+            (datetime.hour(), datetime.minute(), datetime.second()),
+            (16, 29, 0)
+        );
+        assert_eq!(datetime.nanosecond(), 5);
+        assert_eq!(datetime.to_unix(), (1445470140, 5));
+        assert_eq!(
+            datetime.to_offset(UtcOffset::UTC),
+            DateTime::from_unix(1445470140, 5)
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let datetime: DateTime = "2016-12-31T23:59:60.5+01:00".parse().unwrap();
+
+        assert_eq!(datetime.second(), 60);
+        assert_eq!(datetime.offset().minutes(), 60);
+        assert_eq!(datetime.to_unix(), (1483225200, 500_000_000));
+        assert_eq!(
+            datetime.format(Precision::MICROS).unwrap(),
+            "2016-12-31T23:59:60.500000+01:00"
+        );
+    }
+
+    #[test]
+    fn test_format_into() {
+        let datetime = DateTime::from_unix(1445470140, 0).unwrap();
+
+        let mut buf = [0u8; 35];
+        assert_eq!(
+            datetime.format_into(Precision::NANOS, &mut buf),
+            Ok("2015-10-21T23:29:00.000000000Z")
+        );
+
+        let mut buf = [0u8; 19];
+        assert_eq!(
+            datetime.format_into(Precision::Seconds, &mut buf),
+            Err(Error::BufferTooSmall)
+        );
+    }
+}
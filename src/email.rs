@@ -0,0 +1,425 @@
+//! Email dates (RFC 5322 section 3.3).
+//!
+//! The `Date:` header of Internet messages, such as
+//! `Wed, 21 Oct 2015 16:29:00 -0700`. Dates are generated in the current
+//! syntax, while parsing also accepts the obsolete syntax of section 4.3 that
+//! is still found in older mail: two and three digit years, named zones such
+//! as `EST`, and comments and folding whitespace between the tokens.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::buffer::{impl_inline_str, SliceWriter};
+use crate::parse::{check_date, check_time, Cursor, DAY_NAMES, MONTH_NAMES};
+use crate::{DateTime, Error, ParseError, UtcOffset};
+
+/// Zone names of the obsolete syntax with a known offset in minutes. Any other
+/// alphabetic zone, including the military zones, means an unknown offset.
+const ZONE_NAMES: [(&str, i16); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5 * 60),
+    ("EDT", -4 * 60),
+    ("CST", -6 * 60),
+    ("CDT", -5 * 60),
+    ("MST", -7 * 60),
+    ("MDT", -6 * 60),
+    ("PST", -8 * 60),
+    ("PDT", -7 * 60),
+];
+
+/// An email date, e.g. `Wed, 21 Oct 2015 16:29:00 -0700`.
+///
+/// The day of the month always has two digits and the offset of a
+/// [`DateTime`] in [`UtcOffset::UNKNOWN`] is written as `-0000`, which has
+/// the same meaning in RFC 5322.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{DateTime, EmailDate};
+///
+/// // Converting an RFC3339 timestamp into an email date and back.
+/// let datetime: DateTime = "2015-10-21T16:29:00-07:00".parse().unwrap();
+/// let date = EmailDate::from_datetime(&datetime).unwrap();
+/// assert_eq!(date, "Wed, 21 Oct 2015 16:29:00 -0700");
+///
+/// let date: EmailDate = "21 Oct 15 16:29 PDT".parse().unwrap();
+/// assert_eq!(date, "Wed, 21 Oct 2015 16:29:00 -0700");
+/// assert_eq!(date.datetime().to_string(), "2015-10-21T16:29:00.000000-07:00");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmailDate {
+    buf: [u8; EmailDate::LEN],
+    datetime: DateTime,
+}
+
+impl EmailDate {
+    /// The length of an email date.
+    pub const LEN: usize = 31;
+
+    /// Creates an email date from a date and time, dropping any fractional
+    /// second.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the year is before 1900, which
+    /// RFC 5322 does not allow.
+    pub fn from_datetime(datetime: &DateTime) -> Result<EmailDate, Error> {
+        if datetime.year < 1900 {
+            return Err(Error::YearOutOfRange);
+        }
+
+        let datetime = DateTime {
+            nanosecond: 0,
+            ..*datetime
+        };
+        let offset = datetime.offset;
+        let sign = if offset.minutes() < 0 || offset.is_unknown() {
+            '-'
+        } else {
+            '+'
+        };
+        let minutes = offset.minutes().unsigned_abs();
+
+        let mut buf = [0; EmailDate::LEN];
+        let mut writer = SliceWriter::new(&mut buf);
+        fmt::write(
+            &mut writer,
+            format_args!(
+                "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+                DAY_NAMES[datetime.weekday()],
+                datetime.day,
+                MONTH_NAMES[datetime.month as usize - 1],
+                datetime.year,
+                datetime.hour,
+                datetime.minute,
+                datetime.second,
+                sign,
+                minutes / 60,
+                minutes % 60
+            ),
+        )
+        .expect("email dates have a fixed length");
+
+        Ok(EmailDate { buf, datetime })
+    }
+
+    /// Returns the date and time, in local time at the date's offset.
+    pub fn datetime(&self) -> DateTime {
+        self.datetime
+    }
+
+    /// Returns the date as Unix seconds.
+    pub fn to_unix(&self) -> i64 {
+        self.datetime.to_unix().0
+    }
+
+    /// Returns the date as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf).expect("email dates are ASCII")
+    }
+}
+
+impl FromStr for EmailDate {
+    type Err = ParseError;
+
+    /// Parses the current or obsolete syntax, normalizing to the current one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let datetime = parse_email_date(s)?;
+        EmailDate::from_datetime(&datetime).map_err(|_| ParseError::InvalidYear)
+    }
+}
+
+impl From<EmailDate> for DateTime {
+    fn from(date: EmailDate) -> Self {
+        date.datetime
+    }
+}
+
+impl_inline_str!(EmailDate);
+
+/// Formats Unix seconds as an email date in local time at the given offset.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix_offset`] and
+/// [`EmailDate::from_datetime`] return.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_email_date, UtcOffset};
+///
+/// let pdt = UtcOffset::from_minutes(-7 * 60).unwrap();
+/// let date = format_email_date(1445470140, pdt).unwrap();
+/// assert_eq!(date, "Wed, 21 Oct 2015 16:29:00 -0700");
+/// ```
+pub fn format_email_date(seconds: i64, offset: UtcOffset) -> Result<EmailDate, Error> {
+    EmailDate::from_datetime(&DateTime::from_unix_offset(seconds, 0, offset)?)
+}
+
+/// Parses an email date into a date and time at the date's offset.
+///
+/// Both the current and the obsolete syntax are accepted. Two digit years are
+/// taken to be from 1950 to 2049 and three digit years are counted from 1900.
+/// `UT` and `GMT` are UTC, the US zone names have their usual offsets, and
+/// `-0000` or any other zone name gives [`UtcOffset::UNKNOWN`]. The day name
+/// is optional and not checked against the date.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidCharacter`] or [`ParseError::UnexpectedEnd`]
+/// if the input does not match the grammar, [`ParseError::InvalidOffset`] for
+/// an offset outside of -2359 to +2359, and the range errors of
+/// [`parse`](crate::parse) for an invalid date or time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{parse_email_date, UtcOffset};
+///
+/// let datetime = parse_email_date("Wed, 21 Oct 2015 16:29:00 -0700").unwrap();
+/// assert_eq!(datetime.to_unix(), (1445470140, 0));
+///
+/// let datetime = parse_email_date("21 Oct 15 23:29 (a comment) GMT").unwrap();
+/// assert_eq!(datetime.to_unix(), (1445470140, 0));
+/// assert_eq!(datetime.offset(), UtcOffset::UTC);
+/// ```
+pub fn parse_email_date(input: &str) -> Result<DateTime, ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    skip_cfws(&mut cursor)?;
+    if cursor.peek().is_some_and(|byte| byte.is_ascii_alphabetic()) {
+        read_name_ignore_case(&mut cursor, &DAY_NAMES)?;
+        skip_cfws(&mut cursor)?;
+        cursor.expect(b",")?;
+        skip_cfws(&mut cursor)?;
+    }
+
+    let (day, _) = read_number(&mut cursor, 2)?;
+    separator(&mut cursor)?;
+    let month = read_name_ignore_case(&mut cursor, &MONTH_NAMES)? as u32 + 1;
+    separator(&mut cursor)?;
+    let year = match read_number(&mut cursor, 4)? {
+        (year, 2) if year < 50 => 2000 + year,
+        (year, 2 | 3) => 1900 + year,
+        (year, 4) => year,
+        _ => return Err(ParseError::InvalidYear),
+    };
+    separator(&mut cursor)?;
+
+    let hour = cursor.digits(2)?;
+    skip_cfws(&mut cursor)?;
+    cursor.expect(b":")?;
+    skip_cfws(&mut cursor)?;
+    let minute = cursor.digits(2)?;
+    let mut separated = skip_cfws(&mut cursor)?;
+    let mut second = 0;
+    if cursor.peek() == Some(b':') {
+        cursor.pos += 1;
+        skip_cfws(&mut cursor)?;
+        second = cursor.digits(2)?;
+        separated = skip_cfws(&mut cursor)?;
+    }
+    if !separated {
+        return Err(unexpected(&cursor));
+    }
+
+    let offset = match cursor.peek() {
+        Some(sign @ (b'+' | b'-')) => {
+            cursor.pos += 1;
+            let value = cursor.digits(4)?;
+            if value % 100 >= 60 {
+                return Err(ParseError::InvalidOffset);
+            }
+
+            let minutes = (value / 100 * 60 + value % 100) as i16;
+            match sign {
+                b'-' if minutes == 0 => Ok(UtcOffset::UNKNOWN),
+                b'-' => UtcOffset::from_minutes(-minutes),
+                _ => UtcOffset::from_minutes(minutes),
+            }
+            .map_err(|_| ParseError::InvalidOffset)?
+        }
+        _ => {
+            let name = read_word(&mut cursor);
+            if name.is_empty() {
+                return Err(unexpected(&cursor));
+            }
+
+            ZONE_NAMES
+                .iter()
+                .find(|(zone, _)| zone.as_bytes().eq_ignore_ascii_case(name))
+                .map_or(UtcOffset::UNKNOWN, |&(_, minutes)| match minutes {
+                    0 => UtcOffset::UTC,
+                    _ => UtcOffset::from_minutes(minutes).expect("zone offsets are valid"),
+                })
+        }
+    };
+
+    skip_cfws(&mut cursor)?;
+    if cursor.peek().is_some() {
+        return Err(ParseError::TrailingCharacters);
+    }
+
+    check_date(year, month, day)?;
+    check_time(hour, minute, second)?;
+
+    Ok(DateTime {
+        year: year as u16,
+        month: month as u8,
+        day: day as u8,
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        nanosecond: 0,
+        offset,
+    })
+}
+
+/// Returns the error for a missing token at the cursor.
+fn unexpected(cursor: &Cursor<'_>) -> ParseError {
+    match cursor.peek() {
+        Some(_) => ParseError::InvalidCharacter(cursor.pos),
+        None => ParseError::UnexpectedEnd,
+    }
+}
+
+/// Skips folding whitespace and comments, which may be nested and contain
+/// quoted pairs. Returns true if anything was skipped.
+fn skip_cfws(cursor: &mut Cursor<'_>) -> Result<bool, ParseError> {
+    let start = cursor.pos;
+    let mut depth = 0;
+    while let Some(byte) = cursor.peek() {
+        match byte {
+            b'(' => depth += 1,
+            b')' if depth > 0 => depth -= 1,
+            b'\\' if depth > 0 => cursor.pos += 1,
+            b' ' | b'\t' | b'\r' | b'\n' => {}
+            _ if depth > 0 => {}
+            _ => break,
+        }
+        cursor.pos += 1;
+    }
+
+    if depth > 0 {
+        return Err(ParseError::UnexpectedEnd);
+    }
+    Ok(cursor.pos > start)
+}
+
+/// Skips the whitespace or comments required between two tokens.
+fn separator(cursor: &mut Cursor<'_>) -> Result<(), ParseError> {
+    match skip_cfws(cursor)? {
+        true => Ok(()),
+        false => Err(unexpected(cursor)),
+    }
+}
+
+/// Reads one to `max` decimal digits, returning the value and digit count.
+fn read_number(cursor: &mut Cursor<'_>, max: usize) -> Result<(u32, usize), ParseError> {
+    let mut value = 0;
+    let mut count = 0;
+    while let Some(byte @ b'0'..=b'9') = cursor.peek() {
+        if count == max {
+            return Err(ParseError::InvalidCharacter(cursor.pos));
+        }
+        value = value * 10 + (byte - b'0') as u32;
+        count += 1;
+        cursor.pos += 1;
+    }
+
+    match count {
+        0 => Err(unexpected(cursor)),
+        _ => Ok((value, count)),
+    }
+}
+
+/// Reads a run of ASCII letters.
+fn read_word<'a>(cursor: &mut Cursor<'a>) -> &'a [u8] {
+    let start = cursor.pos;
+    while cursor.peek().is_some_and(|byte| byte.is_ascii_alphabetic()) {
+        cursor.pos += 1;
+    }
+    &cursor.input[start..cursor.pos]
+}
+
+/// Reads one of `names`, ignoring ASCII case, returning its index.
+fn read_name_ignore_case(cursor: &mut Cursor<'_>, names: &[&str]) -> Result<usize, ParseError> {
+    let start = cursor.pos;
+    let word = read_word(cursor);
+    names
+        .iter()
+        .position(|name| name.as_bytes().eq_ignore_ascii_case(word))
+        .ok_or_else(|| match word.is_empty() {
+            true => unexpected(cursor),
+            false => ParseError::InvalidCharacter(start),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let date = format_email_date(0, UtcOffset::UNKNOWN).unwrap();
+        assert_eq!(date, "Thu, 01 Jan 1970 00:00:00 -0000");
+
+        let ist = UtcOffset::from_minutes(5 * 60 + 30).unwrap();
+        let date = format_email_date(1445470140, ist).unwrap();
+        assert_eq!(date, "Thu, 22 Oct 2015 04:59:00 +0530");
+
+        let datetime = DateTime::from_unix(-2208988801, 0).unwrap();
+        assert_eq!(
+            EmailDate::from_datetime(&datetime),
+            Err(Error::YearOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_obsolete() {
+        let utc = |input| parse_email_date(input).map(|datetime| datetime.to_unix().0);
+
+        assert_eq!(utc("Wed, 21 Oct 2015 16:29:00 -0700"), Ok(1445470140));
+        assert_eq!(utc("wed , 21 OCT 115 19:29:00 EDT"), Ok(1445470140));
+        assert_eq!(
+            utc(" Wed (Wednesday), 21\r\n Oct 2015 23 : 29 (UTC (really)) +0000 "),
+            Ok(1445470140)
+        );
+        assert_eq!(utc("1 Jan 70 00:00 Z"), Ok(0));
+        assert_eq!(utc("1 Jan 49 00:00 UT"), Ok(2493072000));
+
+        let datetime = parse_email_date("21 Oct 2015 23:29 XYZ").unwrap();
+        assert_eq!(datetime.offset(), UtcOffset::UNKNOWN);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (input, err) in [
+            (
+                "Wed 21 Oct 2015 16:29:00 -0700",
+                ParseError::InvalidCharacter(4),
+            ),
+            ("Wed, 21 Oct 2015 16:29:00", ParseError::UnexpectedEnd),
+            (
+                "Wed, 21 Oct 2015 16:29:00-0700",
+                ParseError::InvalidCharacter(25),
+            ),
+            (
+                "Wed, 21 Okt 2015 16:29:00 -0700",
+                ParseError::InvalidCharacter(8),
+            ),
+            ("Wed, 21 Oct 2015 16:29:00 -0760", ParseError::InvalidOffset),
+            (
+                "Wed, 21 Oct 2015 16:29:00 -0700 x",
+                ParseError::TrailingCharacters,
+            ),
+            ("Wed, 21 Oct 2015 16:29:00 (GMT", ParseError::UnexpectedEnd),
+            ("Wed, 31 Nov 2015 16:29:00 -0700", ParseError::InvalidDay),
+        ] {
+            assert_eq!(parse_email_date(input), Err(err), "{}", input);
+        }
+    }
+}
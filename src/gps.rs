@@ -0,0 +1,217 @@
+//! GPS week number and time of week.
+
+use crate::{DateTime, Error, LeapSeconds, Precision, TimeScale, Timestamp};
+
+const SECONDS_PER_WEEK: u32 = 7 * 86400;
+
+/// A GPS time as a week number since the GPS epoch and time into the week,
+/// as reported by GNSS receivers.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{DateTime, GpsWeekTime, LeapSeconds};
+///
+/// let table = LeapSeconds::BUILTIN;
+/// let time = GpsWeekTime::new(1867, 343757, 0).unwrap();
+/// let datetime = DateTime::from_gps_week(&time, &table).unwrap();
+/// assert_eq!(datetime.to_string(), "2015-10-21T23:29:00.000000Z");
+/// assert_eq!(datetime.to_gps_week(&table), Ok(time));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GpsWeekTime {
+    week: u32,
+    seconds: u32,
+    nanos: u32,
+}
+
+impl GpsWeekTime {
+    /// Creates a GPS time from a full week number, seconds into the week and
+    /// nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidGpsTime`] if `seconds` is not less than a week
+    /// and [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more.
+    pub fn new(week: u32, seconds: u32, nanos: u32) -> Result<GpsWeekTime, Error> {
+        if seconds >= SECONDS_PER_WEEK {
+            return Err(Error::InvalidGpsTime);
+        }
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidSubsecond);
+        }
+
+        Ok(GpsWeekTime {
+            week,
+            seconds,
+            nanos,
+        })
+    }
+
+    /// Creates a GPS time from a week number truncated to `bits` bits, such as
+    /// the 10-bit week of the legacy navigation message, resolving the
+    /// rollover to the first matching week on or after the week of the
+    /// `reference` Unix time.
+    ///
+    /// A reference such as the firmware build date keeps the result correct
+    /// for `2^bits` weeks after it, about 19.6 years with 10 bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidGpsTime`] if `bits` is not between 1 and 31, or
+    /// `week` does not fit into it, [`Error::Overflow`] if the week does not
+    /// fit into a `u32` or the `reference` is close to the limits of an
+    /// `i64`, and any error [`GpsWeekTime::new`] returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{GpsWeekTime, LeapSeconds};
+    ///
+    /// // Week 1867 is reported as 843 after the first rollover in 1999.
+    /// let table = LeapSeconds::BUILTIN;
+    /// let time = GpsWeekTime::from_rollover(843, 10, 343757, 0, 1420070400, &table).unwrap();
+    /// assert_eq!(time.week(), 1867);
+    /// ```
+    pub fn from_rollover(
+        week: u32,
+        bits: u32,
+        seconds: u32,
+        nanos: u32,
+        reference: i64,
+        table: &LeapSeconds<'_>,
+    ) -> Result<GpsWeekTime, Error> {
+        if !(1..=31).contains(&bits) || week >> bits != 0 {
+            return Err(Error::InvalidGpsTime);
+        }
+
+        let reference = TimeScale::Unix.convert(reference, TimeScale::Gps, table)?;
+        let reference_week = reference.max(0) as u64 / SECONDS_PER_WEEK as u64;
+        let modulus = 1u64 << bits;
+
+        let mut full = reference_week - reference_week % modulus + week as u64;
+        if full < reference_week {
+            full += modulus;
+        }
+
+        GpsWeekTime::new(
+            u32::try_from(full).map_err(|_| Error::Overflow)?,
+            seconds,
+            nanos,
+        )
+    }
+
+    /// Creates a GPS time from seconds and nanoseconds since the GPS epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidGpsTime`] if `seconds` is before the GPS epoch
+    /// and [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more.
+    pub fn from_gps_seconds(seconds: i64, nanos: u32) -> Result<GpsWeekTime, Error> {
+        let seconds = u64::try_from(seconds).map_err(|_| Error::InvalidGpsTime)?;
+        let week = seconds / SECONDS_PER_WEEK as u64;
+
+        GpsWeekTime::new(
+            u32::try_from(week).map_err(|_| Error::Overflow)?,
+            (seconds % SECONDS_PER_WEEK as u64) as u32,
+            nanos,
+        )
+    }
+
+    /// Returns the full week number since the GPS epoch.
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// Returns the seconds into the week.
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Returns the nanoseconds into the second.
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Converts into seconds and nanoseconds since the GPS epoch.
+    pub fn to_gps_seconds(&self) -> (i64, u32) {
+        let seconds = self.week as i64 * SECONDS_PER_WEEK as i64 + self.seconds as i64;
+        (seconds, self.nanos)
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from a GPS week number and time of week.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`DateTime::from_gps`] returns.
+    pub fn from_gps_week(time: &GpsWeekTime, table: &LeapSeconds<'_>) -> Result<DateTime, Error> {
+        let (seconds, nanos) = time.to_gps_seconds();
+        DateTime::from_gps(seconds, nanos, table)
+    }
+
+    /// Converts into a GPS week number and time of week.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidGpsTime`] if the time is before the GPS epoch.
+    pub fn to_gps_week(&self, table: &LeapSeconds<'_>) -> Result<GpsWeekTime, Error> {
+        let (seconds, nanos) = self.to_gps(table);
+        GpsWeekTime::from_gps_seconds(seconds, nanos)
+    }
+}
+
+/// Converts a GPS week number and time of week into an RFC3339 formatted
+/// date-time string in UTC with nine fractional digits.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_gps`] returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_gps_week, GpsWeekTime, LeapSeconds};
+///
+/// let time = GpsWeekTime::new(1930, 17, 0).unwrap();
+/// let timestamp = format_gps_week(&time, &LeapSeconds::BUILTIN).unwrap();
+/// assert_eq!(timestamp, "2016-12-31T23:59:60.000000000Z");
+/// ```
+pub fn format_gps_week(time: &GpsWeekTime, table: &LeapSeconds<'_>) -> Result<Timestamp, Error> {
+    DateTime::from_gps_week(time, table)?.format(Precision::NANOS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GpsWeekTime::new(0, 604799, 999_999_999).is_ok());
+        assert_eq!(GpsWeekTime::new(0, 604800, 0), Err(Error::InvalidGpsTime));
+        assert_eq!(
+            GpsWeekTime::from_gps_seconds(-1, 0),
+            Err(Error::InvalidGpsTime)
+        );
+    }
+
+    #[test]
+    fn test_from_rollover() {
+        let table = LeapSeconds::BUILTIN;
+        // 2019-04-07, the second 10-bit rollover.
+        let reference = 1554595200;
+
+        let time = GpsWeekTime::from_rollover(0, 10, 0, 0, reference, &table).unwrap();
+        assert_eq!(time.week(), 2048);
+        let time = GpsWeekTime::from_rollover(1023, 10, 0, 0, reference, &table).unwrap();
+        assert_eq!(time.week(), 3071);
+        let time = GpsWeekTime::from_rollover(2048, 13, 0, 0, reference, &table).unwrap();
+        assert_eq!(time.week(), 2048);
+
+        assert_eq!(
+            GpsWeekTime::from_rollover(1024, 10, 0, 0, reference, &table),
+            Err(Error::InvalidGpsTime)
+        );
+    }
+}
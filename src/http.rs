@@ -0,0 +1,340 @@
+//! HTTP dates (RFC 9110 section 5.6.7).
+//!
+//! HTTP senders use the IMF-fixdate format, `Sun, 06 Nov 1994 08:49:37 GMT`,
+//! while recipients also have to accept the obsolete RFC 850 format,
+//! `Sunday, 06-Nov-94 08:49:37 GMT`, and the asctime format,
+//! `Sun Nov  6 08:49:37 1994`.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::buffer::{impl_inline_str, SliceWriter};
+use crate::parse::{check_date, check_time, Cursor, DAY_NAMES, MONTH_NAMES};
+use crate::{DateTime, Error, ParseError, UtcOffset};
+
+/// Full day names used by RFC 850 dates, starting with Sunday.
+const LONG_DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// An HTTP date in IMF-fixdate format, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+///
+/// Like [`Timestamp`](crate::Timestamp) the string is stored inline and the
+/// type dereferences to a `str`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::HttpDate;
+///
+/// let date: HttpDate = "Sunday, 06-Nov-94 08:49:37 GMT".parse().unwrap();
+/// assert_eq!(date, "Sun, 06 Nov 1994 08:49:37 GMT");
+/// assert_eq!(date.to_unix(), 784111777);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HttpDate {
+    buf: [u8; HttpDate::LEN],
+    seconds: i64,
+}
+
+impl HttpDate {
+    /// The length of an IMF-fixdate.
+    pub const LEN: usize = 29;
+
+    /// Formats Unix seconds as an IMF-fixdate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if `seconds` is outside of
+    /// [`MIN_UNIX_SECONDS`](crate::MIN_UNIX_SECONDS) to
+    /// [`MAX_UNIX_SECONDS`](crate::MAX_UNIX_SECONDS).
+    pub fn from_unix(seconds: i64) -> Result<HttpDate, Error> {
+        let datetime = DateTime::from_unix(seconds, 0)?;
+
+        let mut buf = [0; HttpDate::LEN];
+        let mut writer = SliceWriter::new(&mut buf);
+        fmt::write(
+            &mut writer,
+            format_args!(
+                "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+                DAY_NAMES[datetime.weekday()],
+                datetime.day,
+                MONTH_NAMES[datetime.month as usize - 1],
+                datetime.year,
+                datetime.hour,
+                datetime.minute,
+                datetime.second
+            ),
+        )
+        .expect("IMF-fixdates have a fixed length");
+
+        Ok(HttpDate { buf, seconds })
+    }
+
+    /// Returns the date as Unix seconds.
+    pub fn to_unix(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Returns the date as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf).expect("HTTP dates are ASCII")
+    }
+}
+
+impl FromStr for HttpDate {
+    type Err = ParseError;
+
+    /// Parses any of the three HTTP date formats, normalizing to IMF-fixdate.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seconds = parse_http_date(s)?;
+        HttpDate::from_unix(seconds).map_err(|_| ParseError::InvalidYear)
+    }
+}
+
+impl_inline_str!(HttpDate);
+
+/// Formats Unix seconds as an HTTP date in IMF-fixdate format, for headers
+/// such as `Date`, `Last-Modified` and `Expires`.
+///
+/// # Errors
+///
+/// Returns [`Error::YearOutOfRange`] if `seconds` is outside of
+/// [`MIN_UNIX_SECONDS`](crate::MIN_UNIX_SECONDS) to
+/// [`MAX_UNIX_SECONDS`](crate::MAX_UNIX_SECONDS).
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_http_date;
+///
+/// let date = format_http_date(784111777).unwrap();
+/// assert_eq!(date, "Sun, 06 Nov 1994 08:49:37 GMT");
+/// ```
+pub fn format_http_date(seconds: i64) -> Result<HttpDate, Error> {
+    HttpDate::from_unix(seconds)
+}
+
+/// Parses an HTTP date in IMF-fixdate, RFC 850 or asctime format into Unix
+/// seconds.
+///
+/// The two-digit years of RFC 850 dates are taken to be from 1970 to 2069,
+/// use [`parse_http_date_relative`] to interpret them relative to the current
+/// time as RFC 9110 specifies. Day names must be valid but are not checked
+/// against the date, and a leap second is folded into the following second.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidCharacter`] or [`ParseError::UnexpectedEnd`]
+/// if the input matches none of the formats, and the range errors of
+/// [`parse`](crate::parse) for an invalid date or time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::parse_http_date;
+///
+/// assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(784111777));
+/// assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(784111777));
+/// assert_eq!(parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(784111777));
+/// ```
+pub fn parse_http_date(input: &str) -> Result<i64, ParseError> {
+    parse_with(input, |year| {
+        Ok(if year < 70 { 2000 + year } else { 1900 + year })
+    })
+}
+
+/// Parses an HTTP date like [`parse_http_date`], but interprets a two-digit
+/// year that would be more than 50 years after `now`, in Unix seconds, as the
+/// most recent year in the past with the same last two digits.
+///
+/// # Errors
+///
+/// Returns any error [`parse_http_date`] returns, and
+/// [`ParseError::InvalidYear`] if `now` is out of range or a two-digit year
+/// resolves to a year before 0001.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::parse_http_date_relative;
+///
+/// // In 2026, 76 is 2076 but 77 is 1977.
+/// let now = 1767225600;
+/// let date = parse_http_date_relative("Saturday, 01-Jan-77 00:00:00 GMT", now);
+/// assert_eq!(date, Ok(220924800));
+/// ```
+pub fn parse_http_date_relative(input: &str, now: i64) -> Result<i64, ParseError> {
+    let current = DateTime::from_unix(now, 0)
+        .map_err(|_| ParseError::InvalidYear)?
+        .year as i64;
+
+    parse_with(input, |year| {
+        let mut year = current - current % 100 + year as i64;
+        if year > current + 50 {
+            year -= 100;
+        }
+        // Early in the first century the past year can be before year 1.
+        u32::try_from(year)
+            .ok()
+            .filter(|&year| year >= 1)
+            .ok_or(ParseError::InvalidYear)
+    })
+}
+
+/// Reads a `hh:mm:ss` time of day.
+fn read_time(cursor: &mut Cursor<'_>) -> Result<(u32, u32, u32), ParseError> {
+    let hour = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let minute = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let second = cursor.digits(2)?;
+    Ok((hour, minute, second))
+}
+
+/// Parses an HTTP date, resolving two-digit years with `resolve_year`.
+fn parse_with(
+    input: &str,
+    resolve_year: impl Fn(u32) -> Result<u32, ParseError>,
+) -> Result<i64, ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    // The full names start with the abbreviations, so try them first.
+    let rfc850 = cursor.name(&LONG_DAY_NAMES).is_ok();
+    if !rfc850 {
+        cursor.name(&DAY_NAMES)?;
+    }
+
+    let (year, month, day, (hour, minute, second));
+    if !rfc850 && cursor.peek() == Some(b' ') {
+        // asctime: `Sun Nov  6 08:49:37 1994`
+        cursor.expect(b" ")?;
+        month = cursor.name(&MONTH_NAMES)? as u32 + 1;
+        cursor.expect(b" ")?;
+        day = match cursor.peek() {
+            Some(b' ') => {
+                cursor.pos += 1;
+                cursor.digits(1)?
+            }
+            _ => cursor.digits(2)?,
+        };
+        cursor.expect(b" ")?;
+        (hour, minute, second) = read_time(&mut cursor)?;
+        cursor.expect(b" ")?;
+        year = cursor.digits(4)?;
+    } else {
+        // IMF-fixdate and RFC 850 only differ in the date separators and the
+        // length of the year.
+        cursor.expect(b",")?;
+        cursor.expect(b" ")?;
+        day = cursor.digits(2)?;
+        let separator: &[u8] = if rfc850 { b"-" } else { b" " };
+        cursor.expect(separator)?;
+        month = cursor.name(&MONTH_NAMES)? as u32 + 1;
+        cursor.expect(separator)?;
+        year = match rfc850 {
+            true => resolve_year(cursor.digits(2)?)?,
+            false => cursor.digits(4)?,
+        };
+        cursor.expect(b" ")?;
+        (hour, minute, second) = read_time(&mut cursor)?;
+        cursor.expect(b" ")?;
+        cursor.name(&["GMT"])?;
+    }
+    if cursor.peek().is_some() {
+        return Err(ParseError::TrailingCharacters);
+    }
+
+    check_date(year, month, day)?;
+    check_time(hour, minute, second)?;
+
+    let datetime = DateTime {
+        year: year as u16,
+        month: month as u8,
+        day: day as u8,
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        nanosecond: 0,
+        offset: UtcOffset::UTC,
+    };
+    Ok(datetime.to_unix().0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format_http_date(0).unwrap(),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+        assert_eq!(
+            format_http_date(951782400).unwrap(),
+            "Tue, 29 Feb 2000 00:00:00 GMT"
+        );
+        assert_eq!(
+            format_http_date(crate::MAX_UNIX_SECONDS + 1),
+            Err(Error::YearOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_relative() {
+        // 2026-01-01T00:00:00Z
+        let now = 1767225600;
+        let date = "Sunday, 06-Nov-94 08:49:37 GMT";
+        assert_eq!(parse_http_date_relative(date, now), Ok(784111777));
+
+        // In the year 0037, 99 would be the year -0001.
+        let now = -61_000_000_000;
+        let date = "Sunday, 06-Nov-99 08:49:37 GMT";
+        assert_eq!(
+            parse_http_date_relative(date, now),
+            Err(ParseError::InvalidYear)
+        );
+        let date = "Sunday, 06-Nov-20 08:49:37 GMT";
+        assert!(parse_http_date_relative(date, now).is_ok());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_http_date("Sun Nov 16 08:49:37 1994"), Ok(784975777));
+        assert_eq!(parse_http_date("Thursday, 01-Jan-70 00:00:00 GMT"), Ok(0));
+        assert_eq!(
+            parse_http_date("Sat, 31 Dec 2016 23:59:60 GMT"),
+            Ok(1483228800)
+        );
+
+        for (input, err) in [
+            ("Sun, 06 Nov 1994 08:49:37", ParseError::UnexpectedEnd),
+            (
+                "Sun, 06 Nov 1994 08:49:37 UTC",
+                ParseError::InvalidCharacter(26),
+            ),
+            (
+                "Sun, 6 Nov 1994 08:49:37 GMT",
+                ParseError::InvalidCharacter(6),
+            ),
+            (
+                "Sunday, 06 Nov 1994 08:49:37 GMT",
+                ParseError::InvalidCharacter(10),
+            ),
+            ("Sun, 31 Nov 1994 08:49:37 GMT", ParseError::InvalidDay),
+            (
+                "Sun, 06 Nov 1994 08:49:37 GMT ",
+                ParseError::TrailingCharacters,
+            ),
+        ] {
+            assert_eq!(parse_http_date(input), Err(err), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,486 @@
+//! ISO 8601 calendar dates and times beyond the RFC3339 profile.
+//!
+//! RFC3339 is a strict profile of the ISO 8601 extended format, and
+//! [`parse`](crate::parse) and the [`DateTime`] parser only accept that
+//! profile. This module covers the wider set of ISO 8601 representations
+//! found in filenames and industrial protocols: the basic format without
+//! separators, such as `20151021T232900Z`, reduced precision such as `2015-10`
+//! or `2015-10-21T23`, decimal fractions of hours and minutes, and times
+//! without an offset. Ordinal dates, week dates and expanded years are not
+//! supported.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::buffer::{impl_inline_str, SliceWriter};
+use crate::parse::{check_date, check_time, Cursor};
+use crate::{DateTime, Error, ParseError, Precision, UtcOffset};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// The ISO 8601 format of a date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsoFormat {
+    /// Without separators, e.g. `20151021T232900Z`.
+    Basic,
+    /// With `-` and `:` separators, e.g. `2015-10-21T23:29:00Z`.
+    Extended,
+}
+
+/// The smallest component present in an ISO 8601 date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Resolution {
+    /// A year, e.g. `2015`.
+    Year,
+    /// A month, e.g. `2015-10`.
+    Month,
+    /// A calendar date, e.g. `2015-10-21`.
+    Day,
+    /// An hour, e.g. `2015-10-21T23`.
+    Hour,
+    /// A minute, e.g. `2015-10-21T23:29`.
+    Minute,
+    /// A second, e.g. `2015-10-21T23:29:00`.
+    Second,
+}
+
+impl Resolution {
+    /// Returns the length of the unit in nanoseconds, for times of day.
+    fn unit_nanos(self) -> u64 {
+        match self {
+            Resolution::Hour => 3600 * NANOS_PER_SECOND,
+            Resolution::Minute => 60 * NANOS_PER_SECOND,
+            _ => NANOS_PER_SECOND,
+        }
+    }
+}
+
+/// Options controlling how an ISO 8601 date and time is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoOptions {
+    /// Basic or extended format, defaults to extended.
+    pub format: IsoFormat,
+    /// The smallest component to write, defaults to seconds. Offsets are only
+    /// written with a time of day.
+    pub resolution: Resolution,
+    /// The number of decimal digits of the smallest component if it is a time
+    /// of day, defaults to none.
+    pub precision: Precision,
+}
+
+impl Default for IsoOptions {
+    fn default() -> Self {
+        IsoOptions {
+            format: IsoFormat::Extended,
+            resolution: Resolution::Second,
+            precision: Precision::Seconds,
+        }
+    }
+}
+
+/// An ISO 8601 date and time string.
+///
+/// Like [`Timestamp`](crate::Timestamp) the string is stored inline and the
+/// type dereferences to a `str`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoTimestamp {
+    buf: [u8; IsoTimestamp::MAX_LEN],
+    len: u8,
+}
+
+impl IsoTimestamp {
+    /// The length of the longest possible string, e.g.
+    /// `2015-10-21T23:29:00.123456789+05:30`.
+    pub const MAX_LEN: usize = 35;
+
+    /// Returns the string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len as usize]).expect("timestamps are ASCII")
+    }
+}
+
+impl_inline_str!(IsoTimestamp);
+
+impl DateTime {
+    /// Formats as an ISO 8601 date and time with the given options.
+    ///
+    /// A reduced month is written as `YYYY-MM` in both formats, as ISO 8601
+    /// has no basic form for it. Decimal fractions of the smallest component
+    /// are truncated, and UTC as well as the unknown offset are written as
+    /// `Z`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrecision`] if a fixed precision is not between
+    /// 1 and 9 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{DateTime, IsoFormat, IsoOptions, Precision, Resolution};
+    ///
+    /// let datetime = DateTime::from_unix(1445470140, 0).unwrap();
+    /// let options = IsoOptions {
+    ///     format: IsoFormat::Basic,
+    ///     ..IsoOptions::default()
+    /// };
+    /// assert_eq!(datetime.format_iso8601(options).unwrap(), "20151021T232900Z");
+    ///
+    /// let options = IsoOptions {
+    ///     resolution: Resolution::Hour,
+    ///     precision: Precision::Digits(2),
+    ///     ..IsoOptions::default()
+    /// };
+    /// assert_eq!(datetime.format_iso8601(options).unwrap(), "2015-10-21T23.48Z");
+    /// ```
+    pub fn format_iso8601(&self, options: IsoOptions) -> Result<IsoTimestamp, Error> {
+        options.precision.validate()?;
+
+        let mut buf = [0; IsoTimestamp::MAX_LEN];
+        let mut writer = SliceWriter::new(&mut buf);
+        self.write_iso8601(&mut writer, options)
+            .expect("ISO 8601 timestamps fit into MAX_LEN");
+        let len = writer.into_str().len();
+
+        Ok(IsoTimestamp {
+            buf,
+            len: len as u8,
+        })
+    }
+
+    /// Writes as an ISO 8601 date and time, the precision must be valid.
+    fn write_iso8601<W: fmt::Write>(&self, w: &mut W, options: IsoOptions) -> fmt::Result {
+        let resolution = options.resolution;
+        let (date, time) = match options.format {
+            IsoFormat::Basic => ("", ""),
+            IsoFormat::Extended => ("-", ":"),
+        };
+
+        write!(w, "{:04}", self.year)?;
+        match resolution {
+            Resolution::Year => return Ok(()),
+            Resolution::Month => return write!(w, "-{:02}", self.month),
+            _ => write!(w, "{}{:02}{}{:02}", date, self.month, date, self.day)?,
+        }
+        if resolution == Resolution::Day {
+            return Ok(());
+        }
+
+        write!(w, "T{:02}", self.hour)?;
+        if resolution >= Resolution::Minute {
+            write!(w, "{}{:02}", time, self.minute)?;
+        }
+        if resolution == Resolution::Second {
+            write!(w, "{}{:02}", time, self.second)?;
+        }
+
+        // The fraction of the smallest unit, scaled to nine digits.
+        let remainder = match resolution {
+            Resolution::Hour => self.minute as u64 * 60 + self.second as u64,
+            Resolution::Minute => self.second as u64,
+            _ => 0,
+        } * NANOS_PER_SECOND
+            + self.nanosecond as u64;
+        let fraction =
+            remainder as u128 * NANOS_PER_SECOND as u128 / resolution.unit_nanos() as u128;
+        options
+            .precision
+            .write_fraction(w, fraction.min(999_999_999) as u32)?;
+
+        if self.offset == UtcOffset::UTC || self.offset.is_unknown() {
+            return w.write_str("Z");
+        }
+        let minutes = self.offset.minutes();
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.unsigned_abs();
+        write!(w, "{}{:02}{}{:02}", sign, minutes / 60, time, minutes % 60)
+    }
+}
+
+/// Formats a Unix timestamp as an ISO 8601 date and time in UTC.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix`] and
+/// [`DateTime::format_iso8601`] return.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_iso8601, IsoFormat, IsoOptions, Precision};
+///
+/// let options = IsoOptions {
+///     format: IsoFormat::Basic,
+///     precision: Precision::MILLIS,
+///     ..IsoOptions::default()
+/// };
+/// let timestamp = format_iso8601(1445470140, 250_000_000, options).unwrap();
+/// assert_eq!(timestamp, "20151021T232900.250Z");
+/// ```
+pub fn format_iso8601(
+    seconds: i64,
+    nanos: u32,
+    options: IsoOptions,
+) -> Result<IsoTimestamp, Error> {
+    DateTime::from_unix(seconds, nanos)?.format_iso8601(options)
+}
+
+/// A date and time parsed from any of the supported ISO 8601
+/// representations.
+///
+/// Components below the [`Resolution`] are the start of the period, e.g. the
+/// first of the month, and the offset is optional.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{IsoDateTime, Resolution, UtcOffset};
+///
+/// let iso: IsoDateTime = "20151021T2329Z".parse().unwrap();
+/// assert_eq!(iso.resolution(), Resolution::Minute);
+/// assert_eq!(iso.to_datetime(UtcOffset::UTC).to_unix(), (1445470140, 0));
+///
+/// // A decimal fraction of an hour, in local time without an offset.
+/// let iso: IsoDateTime = "2015-10-21T16,5".parse().unwrap();
+/// assert_eq!(iso.offset(), None);
+/// let pdt = UtcOffset::from_minutes(-7 * 60).unwrap();
+/// assert_eq!(
+///     iso.to_datetime(pdt).to_string(),
+///     "2015-10-21T16:30:00.000000-07:00"
+/// );
+///
+/// // Not valid RFC3339.
+/// assert!(rfc3339::parse("20151021T2329Z").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoDateTime {
+    datetime: DateTime,
+    offset: Option<UtcOffset>,
+    resolution: Resolution,
+}
+
+impl IsoDateTime {
+    /// Parses an ISO 8601 calendar date, optionally followed by a time of day
+    /// and an offset, in basic or extended format.
+    ///
+    /// The date may be reduced to a year or month and the time to hours or
+    /// minutes. The smallest time component may have a decimal fraction of up
+    /// to nine digits after a `.` or `,`. Both formats may not be mixed, and
+    /// the time designator `T` and `Z` may be lowercase as in RFC3339.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse`](crate::parse).
+    pub fn parse(input: &str) -> Result<IsoDateTime, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let (mut month, mut day) = (1, 1);
+        let mut resolution = Resolution::Year;
+
+        let year = cursor.digits(4)?;
+        let extended = cursor.peek() == Some(b'-');
+        if cursor.peek().is_some() {
+            if extended {
+                cursor.pos += 1;
+                month = cursor.digits(2)?;
+                resolution = Resolution::Month;
+                if cursor.peek().is_some() {
+                    cursor.expect(b"-")?;
+                    day = cursor.digits(2)?;
+                    resolution = Resolution::Day;
+                }
+            } else {
+                // The basic format has no reduced month, `YYYYMM` would be
+                // ambiguous.
+                month = cursor.digits(2)?;
+                day = cursor.digits(2)?;
+                resolution = Resolution::Day;
+            }
+        }
+        check_date(year, month, day)?;
+
+        let (mut hour, mut minute, mut second, mut nanos) = (0, 0, 0, 0);
+        let mut offset = None;
+        if cursor.peek().is_some() {
+            if resolution != Resolution::Day {
+                return Err(ParseError::InvalidCharacter(cursor.pos));
+            }
+
+            cursor.expect(b"Tt")?;
+            hour = cursor.digits(2)?;
+            resolution = Resolution::Hour;
+            for field in [&mut minute, &mut second] {
+                match (extended, cursor.peek()) {
+                    (true, Some(b':')) => cursor.pos += 1,
+                    (false, Some(b'0'..=b'9')) => {}
+                    _ => break,
+                }
+                *field = cursor.digits(2)?;
+                resolution = if resolution == Resolution::Hour {
+                    Resolution::Minute
+                } else {
+                    Resolution::Second
+                };
+            }
+
+            if let Some(b'.' | b',') = cursor.peek() {
+                cursor.pos += 1;
+                let fraction = cursor.fraction()?;
+                if resolution == Resolution::Second {
+                    nanos = fraction;
+                } else {
+                    // The fraction of an hour or minute, less than an hour.
+                    let extra = (fraction as u128 * resolution.unit_nanos() as u128
+                        / NANOS_PER_SECOND as u128) as u64;
+                    minute += (extra / (60 * NANOS_PER_SECOND)) as u32;
+                    second = (extra / NANOS_PER_SECOND % 60) as u32;
+                    nanos = (extra % NANOS_PER_SECOND) as u32;
+                }
+            }
+
+            offset = match cursor.peek() {
+                Some(b'Z' | b'z') => {
+                    cursor.pos += 1;
+                    Some(UtcOffset::UTC)
+                }
+                Some(sign @ (b'+' | b'-')) => {
+                    cursor.pos += 1;
+                    Some(cursor.offset(sign, extended.then_some(b':'), true)?)
+                }
+                _ => None,
+            };
+        }
+
+        if cursor.peek().is_some() {
+            return Err(ParseError::TrailingCharacters);
+        }
+        check_time(hour, minute, second)?;
+
+        let datetime = DateTime {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond: nanos,
+            offset: offset.unwrap_or(UtcOffset::UTC),
+        };
+        Ok(IsoDateTime {
+            datetime,
+            offset,
+            resolution,
+        })
+    }
+
+    /// Returns the smallest component present in the input.
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    /// Returns the offset from UTC, or `None` for a date or local time
+    /// without one.
+    pub fn offset(&self) -> Option<UtcOffset> {
+        self.offset
+    }
+
+    /// Returns the date and time, using `default` as the offset if the input
+    /// had none.
+    pub fn to_datetime(&self, default: UtcOffset) -> DateTime {
+        DateTime {
+            offset: self.offset.unwrap_or(default),
+            ..self.datetime
+        }
+    }
+}
+
+impl FromStr for IsoDateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IsoDateTime::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let offset = UtcOffset::from_minutes(5 * 60 + 30).unwrap();
+        let datetime = DateTime::from_unix_offset(1445470140, 500_000_000, offset).unwrap();
+        let format = |format, resolution, precision| {
+            let options = IsoOptions {
+                format,
+                resolution,
+                precision,
+            };
+            datetime.format_iso8601(options).unwrap()
+        };
+
+        use IsoFormat::*;
+        use Resolution::*;
+        assert_eq!(format(Basic, Year, Precision::Auto), "2015");
+        assert_eq!(format(Basic, Month, Precision::Auto), "2015-10");
+        assert_eq!(format(Basic, Day, Precision::Auto), "20151022");
+        assert_eq!(format(Extended, Day, Precision::Auto), "2015-10-22");
+        assert_eq!(format(Basic, Hour, Precision::Seconds), "20151022T04+0530");
+        assert_eq!(
+            format(Extended, Minute, Precision::Auto),
+            "2015-10-22T04:59.008333333+05:30"
+        );
+        assert_eq!(
+            format(Basic, Second, Precision::MILLIS),
+            "20151022T045900.500+0530"
+        );
+        assert_eq!(
+            format(Extended, Second, Precision::NANOS),
+            "2015-10-22T04:59:00.500000000+05:30"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |input| {
+            let iso = IsoDateTime::parse(input)?;
+            Ok::<_, ParseError>((iso.resolution(), iso.to_datetime(UtcOffset::UTC).to_unix()))
+        };
+
+        assert_eq!(parse("2015"), Ok((Resolution::Year, (1420070400, 0))));
+        assert_eq!(parse("2015-10"), Ok((Resolution::Month, (1443657600, 0))));
+        assert_eq!(parse("20151021"), Ok((Resolution::Day, (1445385600, 0))));
+        assert_eq!(
+            parse("2015-10-21T23"),
+            Ok((Resolution::Hour, (1445468400, 0)))
+        );
+        assert_eq!(
+            parse("20151021T2329.25+0100"),
+            Ok((Resolution::Minute, (1445466555, 0)))
+        );
+        assert_eq!(
+            parse("2015-10-21T23:29:00,5-07"),
+            Ok((Resolution::Second, (1445495340, 500_000_000)))
+        );
+        assert_eq!(
+            parse("2015-10-21T23.999999999Z"),
+            Ok((Resolution::Hour, (1445471999, 999_996_400)))
+        );
+
+        let leap = IsoDateTime::parse("2016-12-31T23:59:60,5Z").unwrap();
+        assert_eq!(leap.to_datetime(UtcOffset::UTC).second(), 60);
+
+        for (input, err) in [
+            ("201510", ParseError::UnexpectedEnd),
+            ("2015-1021", ParseError::InvalidCharacter(7)),
+            ("2015-10T23", ParseError::InvalidCharacter(7)),
+            ("20151021T23:29", ParseError::TrailingCharacters),
+            ("2015-10-21T2329", ParseError::TrailingCharacters),
+            ("2015-10-21T23:29+0100", ParseError::TrailingCharacters),
+            ("2015-10-21 23:29", ParseError::InvalidCharacter(10)),
+            ("2015-10-21T23:29.", ParseError::UnexpectedEnd),
+            ("2015-10-21T24", ParseError::InvalidHour),
+        ] {
+            assert_eq!(IsoDateTime::parse(input), Err(err), "{}", input);
+        }
+    }
+}
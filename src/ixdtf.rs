@@ -0,0 +1,607 @@
+//! Internet Extended Date/Time Format (IXDTF) annotations (RFC 9557).
+//!
+//! IXDTF extends an RFC3339 timestamp with bracketed suffixes, a time zone
+//! followed by `key=value` annotations, as in
+//! `2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=hebrew]`. A `!` marks an
+//! annotation as critical, it must not be ignored by the reader.
+
+use core::fmt;
+use core::slice;
+
+use crate::buffer::SliceWriter;
+use crate::parse::parse_datetime_prefix;
+use crate::{DateTime, Error, ParseError, Precision, TimeZone, UtcOffset};
+
+/// A time zone annotation, either a time zone name such as `Europe/Paris` or
+/// a numeric offset such as `+01:00`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeZoneAnnotation<'a> {
+    name: &'a str,
+    critical: bool,
+}
+
+impl<'a> TimeZoneAnnotation<'a> {
+    /// Creates a time zone annotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAnnotation`] if `name` is neither a valid time
+    /// zone name nor a numeric offset.
+    pub fn new(name: &'a str, critical: bool) -> Result<TimeZoneAnnotation<'a>, Error> {
+        check_time_zone(name).map_err(|_| Error::InvalidAnnotation)?;
+        Ok(TimeZoneAnnotation { name, critical })
+    }
+
+    /// Returns the time zone name or numeric offset.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns true if the annotation is marked critical with `!`.
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+
+    /// Returns the offset if the annotation is a numeric offset.
+    pub fn offset(&self) -> Option<UtcOffset> {
+        numeric_offset(self.name)
+    }
+}
+
+/// A `key=value` annotation, such as `u-ca=hebrew` for the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Annotation<'a> {
+    key: &'a str,
+    value: &'a str,
+    critical: bool,
+}
+
+impl<'a> Annotation<'a> {
+    /// Creates a `key=value` annotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAnnotation`] if `key` or `value` is malformed.
+    pub fn new(key: &'a str, value: &'a str, critical: bool) -> Result<Annotation<'a>, Error> {
+        check_key(key).map_err(|_| Error::InvalidAnnotation)?;
+        check_value(value).map_err(|_| Error::InvalidAnnotation)?;
+        Ok(Annotation {
+            key,
+            value,
+            critical,
+        })
+    }
+
+    /// Returns the key.
+    pub fn key(&self) -> &'a str {
+        self.key
+    }
+
+    /// Returns the value, one or more alphanumeric parts joined by `-`.
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Returns true if the annotation is marked critical with `!`.
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
+
+/// An iterator over the `key=value` annotations of an [`Ixdtf`] timestamp.
+#[derive(Debug, Clone)]
+pub struct Annotations<'a> {
+    inner: AnnotationsInner<'a>,
+}
+
+#[derive(Debug, Clone)]
+enum AnnotationsInner<'a> {
+    /// Validated tags from a parsed timestamp.
+    Tags(&'a str),
+    Slice(slice::Iter<'a, Annotation<'a>>),
+}
+
+impl<'a> Iterator for Annotations<'a> {
+    type Item = Annotation<'a>;
+
+    fn next(&mut self) -> Option<Annotation<'a>> {
+        match &mut self.inner {
+            AnnotationsInner::Tags(tags) => {
+                if tags.is_empty() {
+                    return None;
+                }
+                let (tag, len) = parse_tag(tags, 0).expect("validated tags");
+                *tags = &tags[len..];
+                match tag {
+                    Tag::Annotation(annotation) => Some(annotation),
+                    Tag::TimeZone(_) => unreachable!("validated tags"),
+                }
+            }
+            AnnotationsInner::Slice(iter) => iter.next().copied(),
+        }
+    }
+}
+
+/// An RFC3339 timestamp with IXDTF annotations.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::Ixdtf;
+///
+/// let ixdtf = Ixdtf::parse("2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=hebrew]").unwrap();
+/// assert_eq!(ixdtf.datetime().to_unix(), (1657235647, 0));
+/// assert_eq!(ixdtf.time_zone().unwrap().name(), "Europe/Paris");
+/// assert_eq!(ixdtf.calendar(), Some("hebrew"));
+/// assert_eq!(
+///     format!("{:.0}", ixdtf),
+///     "2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=hebrew]"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ixdtf<'a> {
+    datetime: DateTime,
+    time_zone: Option<TimeZoneAnnotation<'a>>,
+    annotations: Annotations<'a>,
+}
+
+impl<'a> Ixdtf<'a> {
+    /// Creates an annotated timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAnnotation`] if the annotations are
+    /// inconsistent, as [`Ixdtf::parse`] would reject them. A critical numeric
+    /// time zone may only differ from the offset if it is
+    /// [`UtcOffset::UNKNOWN`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{Annotation, DateTime, Ixdtf, TimeZoneAnnotation};
+    ///
+    /// let datetime: DateTime = "2022-07-08T00:14:07+01:00".parse().unwrap();
+    /// let time_zone = TimeZoneAnnotation::new("Europe/Paris", true).unwrap();
+    /// let annotations = [Annotation::new("u-ca", "hebrew", false).unwrap()];
+    /// let ixdtf = Ixdtf::new(datetime, Some(time_zone), &annotations).unwrap();
+    /// assert_eq!(
+    ///     format!("{:.0}", ixdtf),
+    ///     "2022-07-08T00:14:07+01:00[!Europe/Paris][u-ca=hebrew]"
+    /// );
+    /// ```
+    pub fn new(
+        datetime: DateTime,
+        time_zone: Option<TimeZoneAnnotation<'a>>,
+        annotations: &'a [Annotation<'a>],
+    ) -> Result<Ixdtf<'a>, Error> {
+        let ixdtf = Ixdtf {
+            datetime,
+            time_zone,
+            annotations: Annotations {
+                inner: AnnotationsInner::Slice(annotations.iter()),
+            },
+        };
+
+        // Only `-00:00` gives the time in UTC alone, `+00:00` is formatted as
+        // `Z`, which RFC 9557 reads as an unknown local offset.
+        ixdtf
+            .check(datetime.offset().is_unknown())
+            .map_err(|_| Error::InvalidAnnotation)?;
+        Ok(ixdtf)
+    }
+
+    /// Parses an RFC3339 timestamp followed by an optional time zone
+    /// annotation and any number of `key=value` annotations.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`parse`](crate::parse) returns for the timestamp,
+    /// [`ParseError::InvalidCharacter`] or [`ParseError::UnexpectedEnd`] for
+    /// malformed annotations and [`ParseError::InconsistentAnnotation`] if a
+    /// critical numeric time zone differs from the offset of the timestamp, or
+    /// a key is repeated and one of its annotations is critical.
+    pub fn parse(input: &'a str) -> Result<Ixdtf<'a>, ParseError> {
+        let (datetime, mut pos) = parse_datetime_prefix(input, b"[")?;
+        // `Z` and `-00:00` only give the time in UTC, so any zone is consistent.
+        let utc_only =
+            matches!(input.as_bytes()[pos - 1], b'Z' | b'z') || datetime.offset().is_unknown();
+
+        let mut time_zone = None;
+        let mut tags = pos;
+        while pos < input.len() {
+            let (tag, len) = parse_tag(&input[pos..], pos)?;
+            if let Tag::TimeZone(annotation) = tag {
+                if tags != pos || time_zone.is_some() {
+                    return Err(ParseError::InvalidCharacter(pos + 1));
+                }
+                time_zone = Some(annotation);
+                tags = pos + len;
+            }
+            pos += len;
+        }
+
+        let ixdtf = Ixdtf {
+            datetime,
+            time_zone,
+            annotations: Annotations {
+                inner: AnnotationsInner::Tags(&input[tags..]),
+            },
+        };
+        ixdtf.check(utc_only)?;
+        Ok(ixdtf)
+    }
+
+    /// Checks the consistency of critical annotations.
+    fn check(&self, utc_only: bool) -> Result<(), ParseError> {
+        if let Some(time_zone) = self.time_zone.filter(|tz| tz.critical && !utc_only) {
+            if let Some(offset) = time_zone.offset() {
+                if offset.minutes() != self.datetime.offset().minutes() {
+                    return Err(ParseError::InconsistentAnnotation);
+                }
+            }
+        }
+
+        for (index, annotation) in self.annotations().enumerate() {
+            let repeated = self
+                .annotations()
+                .skip(index + 1)
+                .filter(|other| other.key == annotation.key);
+            for other in repeated {
+                if annotation.critical || other.critical {
+                    return Err(ParseError::InconsistentAnnotation);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the date and time.
+    pub fn datetime(&self) -> DateTime {
+        self.datetime
+    }
+
+    /// Returns the time zone annotation.
+    pub fn time_zone(&self) -> Option<TimeZoneAnnotation<'a>> {
+        self.time_zone
+    }
+
+    /// Returns an iterator over the `key=value` annotations.
+    pub fn annotations(&self) -> Annotations<'a> {
+        self.annotations.clone()
+    }
+
+    /// Returns the first annotation with the given key.
+    pub fn annotation(&self, key: &str) -> Option<Annotation<'a>> {
+        self.annotations().find(|annotation| annotation.key == key)
+    }
+
+    /// Returns the calendar given by the `u-ca` annotation.
+    pub fn calendar(&self) -> Option<&'a str> {
+        self.annotation("u-ca").map(|annotation| annotation.value)
+    }
+
+    /// Checks a critical time zone annotation against the offset the given
+    /// time zone has at the annotated time, such as the zone looked up by the
+    /// annotated name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InconsistentAnnotation`] if the time zone
+    /// annotation is critical, the timestamp has a local offset and it
+    /// differs from the offset of `zone`.
+    pub fn check_time_zone<Z: TimeZone + ?Sized>(&self, zone: &Z) -> Result<(), ParseError> {
+        let Some(time_zone) = self.time_zone.filter(|tz| tz.critical) else {
+            return Ok(());
+        };
+        let offset = self.datetime.offset();
+        if time_zone.offset().is_some() || offset == UtcOffset::UTC || offset.is_unknown() {
+            return Ok(());
+        }
+
+        let (seconds, _) = self.datetime.to_unix();
+        match zone.utc_offset(seconds) {
+            Ok(expected) if expected.minutes() == offset.minutes() => Ok(()),
+            _ => Err(ParseError::InconsistentAnnotation),
+        }
+    }
+
+    /// Formats into a caller provided buffer, returning the written part of
+    /// the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrecision`] if a fixed precision is not between
+    /// 1 and 9 digits and [`Error::BufferTooSmall`] if the timestamp does not
+    /// fit into `buf`.
+    pub fn format_into<'b>(
+        &self,
+        precision: Precision,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, Error> {
+        precision.validate()?;
+
+        let mut writer = SliceWriter::new(buf);
+        self.datetime
+            .write(&mut writer, precision)
+            .and_then(|_| self.write_annotations(&mut writer))
+            .map_err(|_| Error::BufferTooSmall)?;
+        Ok(writer.into_str())
+    }
+
+    fn write_annotations<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let flag = |critical| if critical { "!" } else { "" };
+
+        if let Some(time_zone) = self.time_zone {
+            write!(w, "[{}{}]", flag(time_zone.critical), time_zone.name)?;
+        }
+        for annotation in self.annotations() {
+            write!(
+                w,
+                "[{}{}={}]",
+                flag(annotation.critical),
+                annotation.key,
+                annotation.value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the timestamp as [`DateTime`] does, followed by the annotations.
+impl fmt::Display for Ixdtf<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.datetime, f)?;
+        self.write_annotations(f)
+    }
+}
+
+enum Tag<'a> {
+    TimeZone(TimeZoneAnnotation<'a>),
+    Annotation(Annotation<'a>),
+}
+
+/// Parses a bracketed tag at the start of `input`, which is at offset `pos` of
+/// the whole input, returning it and its length.
+fn parse_tag(input: &str, pos: usize) -> Result<(Tag<'_>, usize), ParseError> {
+    if !input.starts_with('[') {
+        return Err(ParseError::TrailingCharacters);
+    }
+    let end = input.find(']').ok_or(ParseError::UnexpectedEnd)?;
+
+    let tag = &input[1..end];
+    let (content, critical) = match tag.strip_prefix('!') {
+        Some(content) => (content, true),
+        None => (tag, false),
+    };
+    let start = pos + 1 + critical as usize;
+    let invalid = |offset| ParseError::InvalidCharacter(start + offset);
+
+    let tag = match content.split_once('=') {
+        Some((key, value)) => {
+            check_key(key).map_err(invalid)?;
+            check_value(value).map_err(|offset| invalid(key.len() + 1 + offset))?;
+            Tag::Annotation(Annotation {
+                key,
+                value,
+                critical,
+            })
+        }
+        None => {
+            check_time_zone(content).map_err(invalid)?;
+            Tag::TimeZone(TimeZoneAnnotation {
+                name: content,
+                critical,
+            })
+        }
+    };
+    Ok((tag, end + 1))
+}
+
+/// Checks a suffix key, `[a-z_][a-z0-9_-]*`, returning the offset of the first
+/// invalid byte.
+fn check_key(key: &str) -> Result<(), usize> {
+    let valid = |(index, byte): (usize, u8)| match byte {
+        b'a'..=b'z' | b'_' => true,
+        b'0'..=b'9' | b'-' => index > 0,
+        _ => false,
+    };
+
+    match key.bytes().enumerate().position(|pair| !valid(pair)) {
+        Some(offset) => Err(offset),
+        None if key.is_empty() => Err(0),
+        None => Ok(()),
+    }
+}
+
+/// Checks a suffix value, alphanumeric parts joined by `-`, returning the
+/// offset of the first invalid byte.
+fn check_value(value: &str) -> Result<(), usize> {
+    let mut offset = 0;
+    for part in value.split('-') {
+        if let Some(index) = part.bytes().position(|byte| !byte.is_ascii_alphanumeric()) {
+            return Err(offset + index);
+        }
+        if part.is_empty() {
+            return Err(offset);
+        }
+        offset += part.len() + 1;
+    }
+    Ok(())
+}
+
+/// Checks a time zone name or numeric offset, returning the offset of the
+/// first invalid byte.
+fn check_time_zone(name: &str) -> Result<(), usize> {
+    if name.starts_with(['+', '-']) {
+        return numeric_offset(name).map(|_| ()).ok_or(0);
+    }
+
+    let valid = |(index, byte): (usize, u8)| match byte {
+        b'a'..=b'z' | b'A'..=b'Z' | b'.' | b'_' => true,
+        b'0'..=b'9' | b'-' | b'+' => index > 0,
+        _ => false,
+    };
+
+    let mut offset = 0;
+    for part in name.split('/') {
+        if let Some(index) = part.bytes().enumerate().position(|pair| !valid(pair)) {
+            return Err(offset + index);
+        }
+        if part.is_empty() || part == "." || part == ".." {
+            return Err(offset);
+        }
+        offset += part.len() + 1;
+    }
+    Ok(())
+}
+
+/// Parses a numeric offset, `(+|-)hh[[:]mm]`.
+fn numeric_offset(name: &str) -> Option<UtcOffset> {
+    let (sign, rest) = match name.as_bytes().split_first()? {
+        (b'+', rest) => (1, rest),
+        (b'-', rest) => (-1, rest),
+        _ => return None,
+    };
+    let (hours, minutes) = match rest {
+        [h1, h2] => ([*h1, *h2], [b'0', b'0']),
+        [h1, h2, m1, m2] | [h1, h2, b':', m1, m2] => ([*h1, *h2], [*m1, *m2]),
+        _ => return None,
+    };
+
+    let number = |digits: [u8; 2]| {
+        let valid = digits.iter().all(u8::is_ascii_digit);
+        valid.then(|| ((digits[0] - b'0') * 10 + (digits[1] - b'0')) as i16)
+    };
+    let (hours, minutes) = (number(hours)?, number(minutes)?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    UtcOffset::from_minutes(sign * (hours * 60 + minutes)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let ixdtf = Ixdtf::parse("2022-07-08T00:14:07Z[!u-ca=hebrew][_x=a-1]").unwrap();
+        assert_eq!(ixdtf.time_zone(), None);
+        let annotations: [Annotation; 2] = [
+            ixdtf.annotations().next().unwrap(),
+            ixdtf.annotations().nth(1).unwrap(),
+        ];
+        assert_eq!(
+            annotations,
+            [
+                Annotation::new("u-ca", "hebrew", true).unwrap(),
+                Annotation::new("_x", "a-1", false).unwrap(),
+            ]
+        );
+        assert_eq!(ixdtf.annotations().count(), 2);
+
+        let ixdtf = Ixdtf::parse("2022-07-08T00:14:07+01:00[!+0100]").unwrap();
+        let offset = ixdtf.time_zone().unwrap().offset();
+        assert_eq!(offset, UtcOffset::from_minutes(60).ok());
+
+        let ixdtf = Ixdtf::parse("2022-07-08T00:14:07Z").unwrap();
+        assert_eq!(ixdtf.annotations().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (input, err) in [
+            ("2022-07-08T00:14:07Z[", ParseError::UnexpectedEnd),
+            ("2022-07-08T00:14:07Z[]", ParseError::InvalidCharacter(21)),
+            (
+                "2022-07-08T00:14:07Z[Europe/../x]",
+                ParseError::InvalidCharacter(28),
+            ),
+            (
+                "2022-07-08T00:14:07Z[a=b][UTC]",
+                ParseError::InvalidCharacter(26),
+            ),
+            (
+                "2022-07-08T00:14:07Z[UTC][UTC]",
+                ParseError::InvalidCharacter(26),
+            ),
+            (
+                "2022-07-08T00:14:07Z[A=b]",
+                ParseError::InvalidCharacter(21),
+            ),
+            (
+                "2022-07-08T00:14:07Z[a=b-]",
+                ParseError::InvalidCharacter(25),
+            ),
+            ("2022-07-08T00:14:07Z[UTC]x", ParseError::TrailingCharacters),
+            ("2022-07-08T00:14:07Zx", ParseError::TrailingCharacters),
+        ] {
+            assert_eq!(Ixdtf::parse(input).unwrap_err(), err, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        for input in [
+            "2022-07-08T00:14:07+01:00[!+02:00]",
+            "2022-07-08T00:14:07Z[!u-ca=hebrew][u-ca=iso8601]",
+            "2022-07-08T00:14:07Z[u-ca=hebrew][!u-ca=iso8601]",
+        ] {
+            assert_eq!(
+                Ixdtf::parse(input).unwrap_err(),
+                ParseError::InconsistentAnnotation,
+                "{}",
+                input
+            );
+        }
+
+        // Not critical, or the timestamp only gives the time in UTC.
+        assert!(Ixdtf::parse("2022-07-08T00:14:07+01:00[+02:00]").is_ok());
+        assert!(Ixdtf::parse("2022-07-08T00:14:07Z[!+02:00]").is_ok());
+        assert!(Ixdtf::parse("2022-07-08T00:14:07-00:00[!+02:00]").is_ok());
+        assert!(Ixdtf::parse("2022-07-08T00:14:07Z[u-ca=hebrew][u-ca=iso8601]").is_ok());
+
+        // Creating agrees with parsing.
+        for (input, consistent) in [
+            ("2022-07-08T00:14:07+00:00[!+01:00]", false),
+            ("2022-07-08T00:14:07-00:00[!+01:00]", true),
+            ("2022-07-08T00:14:07+01:00[!+01:00]", true),
+            ("2022-07-08T00:14:07+02:00[!+01:00]", false),
+        ] {
+            let (datetime, time_zone) = input.split_once('[').unwrap();
+            let datetime: DateTime = datetime.parse().unwrap();
+            let time_zone = TimeZoneAnnotation::new(&time_zone[1..7], true).unwrap();
+            assert_eq!(Ixdtf::parse(input).is_ok(), consistent, "{}", input);
+            assert_eq!(
+                Ixdtf::new(datetime, Some(time_zone), &[]).is_ok(),
+                consistent,
+                "{}",
+                input
+            );
+        }
+
+        let ixdtf = Ixdtf::parse("2022-07-08T00:14:07+01:00[!Europe/Paris]").unwrap();
+        let cest = UtcOffset::from_minutes(120).unwrap();
+        assert_eq!(
+            ixdtf.check_time_zone(&cest),
+            Err(ParseError::InconsistentAnnotation)
+        );
+    }
+
+    #[test]
+    fn test_format_into() {
+        let ixdtf = Ixdtf::parse("2022-07-08T00:14:07+01:00[!Europe/Paris][u-ca=hebrew]").unwrap();
+
+        let mut buf = [0u8; 64];
+        assert_eq!(
+            ixdtf.format_into(Precision::MILLIS, &mut buf),
+            Ok("2022-07-08T00:14:07.000+01:00[!Europe/Paris][u-ca=hebrew]")
+        );
+        assert_eq!(
+            ixdtf.format_into(Precision::Seconds, &mut buf[..40]),
+            Err(Error::BufferTooSmall)
+        );
+    }
+}
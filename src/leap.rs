@@ -0,0 +1,390 @@
+//! Leap second tables and leap second aware formatting and parsing.
+//!
+//! Unix time has no representation for a leap second, so during one POSIX
+//! clocks repeat the Unix time of 23:59:59. A [`LeapSeconds`] table tells when
+//! this happened so that the repeated second can be formatted as `23:59:60`,
+//! and parsed `:60` seconds can be validated.
+
+use core::fmt;
+
+use crate::ntp::NTP_UNIX_OFFSET;
+use crate::parse::parse_datetime;
+use crate::{DateTime, Error, ParseError};
+
+const BUILTIN_ENTRIES: [LeapSecond; 28] = [
+    LeapSecond::new(63072000, 10),
+    LeapSecond::new(78796800, 11),
+    LeapSecond::new(94694400, 12),
+    LeapSecond::new(126230400, 13),
+    LeapSecond::new(157766400, 14),
+    LeapSecond::new(189302400, 15),
+    LeapSecond::new(220924800, 16),
+    LeapSecond::new(252460800, 17),
+    LeapSecond::new(283996800, 18),
+    LeapSecond::new(315532800, 19),
+    LeapSecond::new(362793600, 20),
+    LeapSecond::new(394329600, 21),
+    LeapSecond::new(425865600, 22),
+    LeapSecond::new(489024000, 23),
+    LeapSecond::new(567993600, 24),
+    LeapSecond::new(631152000, 25),
+    LeapSecond::new(662688000, 26),
+    LeapSecond::new(709948800, 27),
+    LeapSecond::new(741484800, 28),
+    LeapSecond::new(773020800, 29),
+    LeapSecond::new(820454400, 30),
+    LeapSecond::new(867715200, 31),
+    LeapSecond::new(915148800, 32),
+    LeapSecond::new(1136073600, 33),
+    LeapSecond::new(1230768000, 34),
+    LeapSecond::new(1341100800, 35),
+    LeapSecond::new(1435708800, 36),
+    LeapSecond::new(1483228800, 37),
+];
+
+/// A change of the offset between TAI and UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// The Unix time from which the offset applies, midnight UTC right after
+    /// the leap second.
+    pub unix: i64,
+    /// TAI - UTC in seconds from this time on.
+    pub tai_offset: i32,
+}
+
+impl LeapSecond {
+    /// Creates a leap second table entry.
+    pub const fn new(unix: i64, tai_offset: i32) -> Self {
+        LeapSecond { unix, tai_offset }
+    }
+}
+
+/// A table of leap seconds, sorted by time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::LeapSeconds;
+///
+/// let table = LeapSeconds::BUILTIN;
+/// assert_eq!(table.tai_offset(1483228800), 37);
+/// assert!(table.leap_second_before(1483228800));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSeconds<'a> {
+    entries: &'a [LeapSecond],
+    expires: Option<i64>,
+}
+
+impl<'a> LeapSeconds<'a> {
+    /// The leap seconds from the IANA `leap-seconds.list` valid until
+    /// 2026-12-28, the last one being at the end of 2016.
+    pub const BUILTIN: LeapSeconds<'static> = LeapSeconds {
+        entries: &BUILTIN_ENTRIES,
+        expires: Some(1798416000),
+    };
+
+    /// Creates a table from entries sorted by time, with an optional Unix time
+    /// after which it is no longer known to be complete.
+    pub const fn new(entries: &'a [LeapSecond], expires: Option<i64>) -> Self {
+        LeapSeconds { entries, expires }
+    }
+
+    /// Parses an IERS / IANA `leap-seconds.list` file, storing the entries in
+    /// `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LeapSecondsError::InvalidLine`] for a malformed or out of
+    /// order entry and [`LeapSecondsError::TooManyEntries`] if `buf` is too
+    /// small.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{LeapSecond, LeapSeconds};
+    ///
+    /// let list = "#@\t3991593600\n2272060800\t10\t# 1 Jan 1972\n";
+    /// let mut buf = [LeapSecond::new(0, 0); 32];
+    /// let table = LeapSeconds::parse_list(list, &mut buf).unwrap();
+    /// assert_eq!(table.entries(), [LeapSecond::new(63072000, 10)]);
+    /// assert_eq!(table.expires(), Some(1782604800));
+    /// ```
+    pub fn parse_list(
+        input: &str,
+        buf: &'a mut [LeapSecond],
+    ) -> Result<LeapSeconds<'a>, LeapSecondsError> {
+        let mut len = 0;
+        let mut expires = None;
+
+        for (number, line) in input.lines().enumerate() {
+            let invalid = LeapSecondsError::InvalidLine(number + 1);
+
+            if let Some(rest) = line.strip_prefix("#@") {
+                let ntp: i64 = rest.trim().parse().map_err(|_| invalid)?;
+                expires = Some(ntp.checked_sub(NTP_UNIX_OFFSET).ok_or(invalid)?);
+                continue;
+            }
+
+            let data = line.split('#').next().unwrap_or_default();
+            let mut fields = data.split_whitespace();
+            let (Some(ntp), Some(offset)) = (fields.next(), fields.next()) else {
+                if data.trim().is_empty() {
+                    continue;
+                }
+                return Err(invalid);
+            };
+
+            let ntp: i64 = ntp.parse().map_err(|_| invalid)?;
+            let entry = LeapSecond::new(
+                ntp.checked_sub(NTP_UNIX_OFFSET).ok_or(invalid)?,
+                offset.parse().map_err(|_| invalid)?,
+            );
+            if fields.next().is_some() || len > 0 && buf[len - 1].unix >= entry.unix {
+                return Err(invalid);
+            }
+
+            *buf.get_mut(len).ok_or(LeapSecondsError::TooManyEntries)? = entry;
+            len += 1;
+        }
+
+        Ok(LeapSeconds::new(&buf[..len], expires))
+    }
+
+    /// Returns the entries of the table.
+    pub fn entries(&self) -> &'a [LeapSecond] {
+        self.entries
+    }
+
+    /// Returns the Unix time after which the table is no longer known to be
+    /// complete, if known.
+    pub fn expires(&self) -> Option<i64> {
+        self.expires
+    }
+
+    /// Returns TAI - UTC in seconds at the given Unix time.
+    ///
+    /// Before the first entry the offset of the first entry is returned, as
+    /// UTC did not have whole second offsets before 1972.
+    pub fn tai_offset(&self, unix: i64) -> i32 {
+        let index = self.entries.partition_point(|entry| entry.unix <= unix);
+        match index {
+            0 => self.entries.first().map_or(0, |entry| entry.tai_offset),
+            _ => self.entries[index - 1].tai_offset,
+        }
+    }
+
+    /// Returns true if a leap second is inserted right before the given Unix
+    /// time, i.e. the UTC day before it ends at `23:59:60`.
+    pub fn leap_second_before(&self, unix: i64) -> bool {
+        match self.entries.binary_search_by_key(&unix, |entry| entry.unix) {
+            Ok(0) => false,
+            Ok(index) => self.entries[index].tai_offset > self.entries[index - 1].tai_offset,
+            Err(_) => false,
+        }
+    }
+}
+
+impl Default for LeapSeconds<'_> {
+    fn default() -> Self {
+        LeapSeconds::BUILTIN
+    }
+}
+
+/// An error returned when a leap second list could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapSecondsError {
+    /// The line with the given (1-based) number is malformed or out of order.
+    InvalidLine(usize),
+    /// The buffer is too small to hold all entries.
+    TooManyEntries,
+}
+
+impl fmt::Display for LeapSecondsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeapSecondsError::InvalidLine(line) => write!(f, "invalid entry on line {}", line),
+            LeapSecondsError::TooManyEntries => f.write_str("too many entries"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LeapSecondsError {}
+
+/// How a parsed leap second (`:60`) is converted into Unix time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapSecondPolicy {
+    /// Fail with [`ParseError::LeapSecond`].
+    Reject,
+    /// Use the last nanosecond of the preceding second, so the result is never
+    /// after the start of the following minute.
+    Clamp,
+    /// Use the start of the following second, like [`parse`](crate::parse).
+    Map,
+}
+
+/// Parses an RFC3339 `date-time` into Unix seconds and nanoseconds in UTC,
+/// handling leap seconds according to `policy`.
+///
+/// Unless rejected, a leap second must be listed in `table`, otherwise
+/// [`ParseError::InvalidSecond`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{parse_with_leap_seconds, LeapSecondPolicy, LeapSeconds, ParseError};
+///
+/// let table = LeapSeconds::BUILTIN;
+/// let leap = "2016-12-31T23:59:60.5Z";
+///
+/// assert_eq!(
+///     parse_with_leap_seconds(leap, LeapSecondPolicy::Reject, &table),
+///     Err(ParseError::LeapSecond)
+/// );
+/// assert_eq!(
+///     parse_with_leap_seconds(leap, LeapSecondPolicy::Clamp, &table),
+///     Ok((1483228799, 999_999_999))
+/// );
+/// assert_eq!(
+///     parse_with_leap_seconds(leap, LeapSecondPolicy::Map, &table),
+///     Ok((1483228800, 500_000_000))
+/// );
+/// ```
+pub fn parse_with_leap_seconds(
+    input: &str,
+    policy: LeapSecondPolicy,
+    table: &LeapSeconds<'_>,
+) -> Result<(i64, u32), ParseError> {
+    let datetime = parse_datetime(input)?;
+    let (seconds, nanos) = datetime.to_unix();
+    if !datetime.is_leap_second() {
+        return Ok((seconds, nanos));
+    }
+
+    if policy == LeapSecondPolicy::Reject {
+        return Err(ParseError::LeapSecond);
+    }
+    if !table.leap_second_before(seconds) {
+        return Err(ParseError::InvalidSecond);
+    }
+
+    match policy {
+        LeapSecondPolicy::Clamp => Ok((seconds - 1, 999_999_999)),
+        _ => Ok((seconds, nanos)),
+    }
+}
+
+impl DateTime {
+    /// Creates the leap second `23:59:60` in UTC that follows the Unix time
+    /// `seconds`, which POSIX clocks repeat during the leap second.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLeapSecond`] if `table` has no leap second after
+    /// `seconds`, and any error [`DateTime::from_unix`] returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{DateTime, LeapSeconds};
+    ///
+    /// let datetime = DateTime::from_unix_leap_second(1483228799, 0, &LeapSeconds::BUILTIN).unwrap();
+    /// assert_eq!(datetime.to_string(), "2016-12-31T23:59:60.000000Z");
+    /// ```
+    pub fn from_unix_leap_second(
+        seconds: i64,
+        nanos: u32,
+        table: &LeapSeconds<'_>,
+    ) -> Result<DateTime, Error> {
+        let next = seconds.checked_add(1).ok_or(Error::Overflow)?;
+        if !table.leap_second_before(next) {
+            return Err(Error::InvalidLeapSecond);
+        }
+
+        let datetime = DateTime::from_unix(seconds, nanos)?;
+        Ok(DateTime {
+            second: 60,
+            ..datetime
+        })
+    }
+
+    /// Returns true if this is a leap second, i.e. the second is 60.
+    pub fn is_leap_second(&self) -> bool {
+        self.second == 60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin() {
+        let table = LeapSeconds::BUILTIN;
+
+        assert_eq!(table.tai_offset(0), 10);
+        assert_eq!(table.tai_offset(1483228799), 36);
+        assert_eq!(table.tai_offset(1483228800), 37);
+        assert!(!table.leap_second_before(63072000));
+        assert!(table.leap_second_before(1435708800));
+        assert!(!table.leap_second_before(1435708801));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let list = "# comment\n\n2272060800 10 # 1 Jan 1972\n2287785600\t11\n";
+        let mut buf = [LeapSecond::new(0, 0); 2];
+        let table = LeapSeconds::parse_list(list, &mut buf).unwrap();
+        assert_eq!(table.entries().len(), 2);
+        assert!(table.leap_second_before(78796800));
+
+        let mut buf = [LeapSecond::new(0, 0); 1];
+        assert_eq!(
+            LeapSeconds::parse_list(list, &mut buf),
+            Err(LeapSecondsError::TooManyEntries)
+        );
+
+        for (list, line) in [
+            ("2287785600 11\n2272060800 10\n", 2),
+            ("-9223372036854775808 10\n", 1),
+            ("#@ -9223372036854775808\n", 1),
+        ] {
+            let mut buf = [LeapSecond::new(0, 0); 2];
+            assert_eq!(
+                LeapSeconds::parse_list(list, &mut buf),
+                Err(LeapSecondsError::InvalidLine(line)),
+                "{}",
+                list
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_with_leap_seconds() {
+        let table = LeapSeconds::BUILTIN;
+
+        assert_eq!(
+            parse_with_leap_seconds("2015-07-01T01:59:60+02:00", LeapSecondPolicy::Map, &table),
+            Ok((1435708800, 0))
+        );
+        assert_eq!(
+            parse_with_leap_seconds("2015-06-30T12:59:60Z", LeapSecondPolicy::Map, &table),
+            Err(ParseError::InvalidSecond)
+        );
+    }
+
+    #[test]
+    fn test_from_unix_leap_second() {
+        let table = LeapSeconds::BUILTIN;
+
+        let datetime = DateTime::from_unix_leap_second(1435708799, 0, &table).unwrap();
+        assert!(datetime.is_leap_second());
+        assert_eq!(datetime.to_unix(), (1435708800, 0));
+
+        assert_eq!(
+            DateTime::from_unix_leap_second(1435708800, 0, &table),
+            Err(Error::InvalidLeapSecond)
+        );
+    }
+}
@@ -6,44 +6,163 @@
 //!
 //! ## Features
 //! - No standard library dependency when built with default features disabled.
-//! - Supports heapless operation for embedded environments.
-//! 
+//! - Heap backed helpers with the `alloc` feature, which does not need `std`.
+//! - Serde support with the `serde` feature, see [`serde`](crate::serde).
+//! - Named time zones without a filesystem with the `tzdb` features, see
+//!   [`tzdb`](crate::tzdb).
+//! - Supports allocation free operation for embedded environments.
+//!
 //! ## Usage
-//! 
+//!
 //! ```rust
 //! use rfc3339::format_unix;
-//! 
+//!
 //! let timestamp = format_unix(1609459200, 0);
 //! assert_eq!(timestamp, "2021-01-01T00:00:00.000000Z");
 //! ```
-//! 
+//!
+//! Timestamps can be parsed back into Unix seconds and nanoseconds:
+//!
+//! ```rust
+//! use rfc3339::parse;
+//!
+//! let (seconds, nanos) = parse("2021-01-01T01:00:00.5+01:00").unwrap();
+//! assert_eq!(seconds, 1609459200);
+//! assert_eq!(nanos, 500_000_000);
+//! ```
+//!
 //! ## References
-//! 
+//!
 //! - [RFC3339](https://tools.ietf.org/html/rfc3339)
 //! - [Unix Timestamp](https://en.wikipedia.org/wiki/Unix_time)
 //! - [Rata Die](https://en.wikipedia.org/wiki/Rata_Die)
-//! 
+//!
 //! ## License
-//! 
+//!
 //! Licensed under the Mozilla Public License, v. 2.0, see LICENSE for details.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use core::fmt::Write;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[cfg(not(feature = "std"))]
-use heapless::String;
+use core::fmt;
+
+mod buffer;
+mod datetime;
+mod email;
+mod gps;
+mod http;
+mod iso8601;
+mod ixdtf;
+mod leap;
+mod ntp;
+mod offset;
+mod parse;
+mod posix;
+mod precision;
+mod ptp;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod syslog;
+mod time;
+mod timescale;
+mod timestamp;
+#[cfg(feature = "tzdb")]
+pub mod tzdb;
+mod tzif;
+mod zone;
+
+pub use datetime::DateTime;
+pub use email::{format_email_date, parse_email_date, EmailDate};
+pub use gps::{format_gps_week, GpsWeekTime};
+pub use http::{format_http_date, parse_http_date, parse_http_date_relative, HttpDate};
+pub use iso8601::{format_iso8601, IsoDateTime, IsoFormat, IsoOptions, IsoTimestamp, Resolution};
+pub use ixdtf::{Annotation, Annotations, Ixdtf, TimeZoneAnnotation};
+pub use leap::{
+    parse_with_leap_seconds, LeapSecond, LeapSecondPolicy, LeapSeconds, LeapSecondsError,
+};
+pub use ntp::{NtpShort, NtpTimestamp};
+pub use offset::UtcOffset;
+pub use parse::{parse, ParseError};
+pub use posix::{PosixTz, PosixTzError};
+pub use precision::Precision;
+pub use ptp::PtpTimestamp;
+pub use syslog::{format_rfc3164, format_rfc5424, parse_rfc3164, parse_rfc5424, BsdTimestamp};
+pub use time::format_duration;
+#[cfg(feature = "std")]
+pub use time::{format_system_time, parse_system_time};
+pub use timescale::{format_gps, format_tai, TimeScale, GPS_EPOCH_UNIX, TAI_GPS_OFFSET};
+pub use timestamp::Timestamp;
+pub use tzif::{Tzif, TzifError};
+pub use zone::{format_unix_zone, LocalTimeType, TimeZone};
 
 const SECONDS_PER_DAY: u64 = 86400;
 const DAY_OFFSETS: [u64; 13] = [0, 306, 337, 0, 31, 61, 92, 122, 153, 184, 214, 245, 275];
 // Unix epoch in seconds (Gregorian calendar).
 const UNIX_EPOCH: u64 = 62135683200;
 
-/// A timestamp in RFC3339 format.
+/// The earliest Unix time that can be formatted, `0001-01-01T00:00:00Z`.
+pub const MIN_UNIX_SECONDS: i64 = -62135596800;
+/// The latest Unix time that can be formatted, `9999-12-31T23:59:59Z`.
+pub const MAX_UNIX_SECONDS: i64 = 253402300799;
+
+/// An error returned when a timestamp cannot be formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The sub-second part is not less than one second.
+    InvalidSubsecond,
+    /// The year would fall outside of the 0001 to 9999 range of RFC3339.
+    YearOutOfRange,
+    /// The UTC offset is outside of -23:59 to +23:59.
+    InvalidOffset,
+    /// A fixed precision is not between 1 and 9 digits.
+    InvalidPrecision,
+    /// The input does not fit into the arithmetic used for conversion.
+    Overflow,
+    /// The output buffer is too small to hold the timestamp.
+    BufferTooSmall,
+    /// The leap second table has no leap second at the given time.
+    InvalidLeapSecond,
+    /// A GPS week number or time of week is out of range.
+    InvalidGpsTime,
+    /// A PTP timestamp is out of range.
+    InvalidPtpTime,
+    /// An IXDTF annotation is malformed or inconsistent.
+    InvalidAnnotation,
+    /// The time zone does not know its offset at the given time.
+    UnknownOffset,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSubsecond => f.write_str("sub-second part out of range"),
+            Error::YearOutOfRange => f.write_str("year out of range"),
+            Error::InvalidOffset => f.write_str("utc offset out of range"),
+            Error::InvalidPrecision => f.write_str("precision out of range"),
+            Error::Overflow => f.write_str("arithmetic overflow"),
+            Error::BufferTooSmall => f.write_str("buffer too small"),
+            Error::InvalidLeapSecond => f.write_str("no leap second at this time"),
+            Error::InvalidGpsTime => f.write_str("gps time out of range"),
+            Error::InvalidPtpTime => f.write_str("ptp time out of range"),
+            Error::InvalidAnnotation => f.write_str("invalid annotation"),
+            Error::UnknownOffset => f.write_str("offset unknown at this time"),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
-pub type Timestamp = String;
-#[cfg(not(feature = "std"))]
-pub type Timestamp = String<27>;
+impl std::error::Error for Error {}
+
+/// Options controlling how a timestamp is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FormatOptions {
+    /// The offset from UTC of the local time, defaults to UTC.
+    pub offset: UtcOffset,
+    /// The number of fractional second digits, defaults to six.
+    pub precision: Precision,
+}
 
 /// Converts a Unix timestamp into an RFC3339 formatted date-time string in UTC.
 ///
@@ -52,29 +171,321 @@ pub type Timestamp = String<27>;
 /// * `seconds` - The number of seconds since Unix Epoch.
 /// * `micros` - Microseconds part to be included in the timestamp.
 ///
+/// Whole seconds in `micros` of 1,000,000 or more carry over into `seconds`.
+/// Times after [`MAX_UNIX_SECONDS`] saturate to
+/// `9999-12-31T23:59:59.999999Z`, use [`try_format_unix`] if the input is not
+/// known to be in range.
+///
 /// # Examples
 ///
 /// ```rust
 /// use rfc3339::format_unix;
-/// 
+///
 /// let timestamp = format_unix(1609459200, 0);
 /// assert_eq!(timestamp, "2021-01-01T00:00:00.000000Z");
 /// ```
 pub fn format_unix(seconds: u64, micros: u32) -> Timestamp {
-    let days_since_epoch = (seconds + UNIX_EPOCH) / SECONDS_PER_DAY;
-    let (year, month, day) = rdn_to_ymd(days_since_epoch);
-    let sec = (seconds + UNIX_EPOCH) % SECONDS_PER_DAY;
-    let hour = sec / 3600;
-    let minute = (sec % 3600) / 60;
-    let second = sec % 60;
-
-    let mut output = Timestamp::new();
-    let _ = write!(
-        output,
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
-        year, month, day, hour, minute, second, micros
-    );
-    output
+    let micros = micros as u64;
+    let seconds = seconds.saturating_add(micros / 1_000_000);
+    let (seconds, micros) = if seconds > MAX_UNIX_SECONDS as u64 {
+        (MAX_UNIX_SECONDS as u64, 999_999)
+    } else {
+        (seconds, micros % 1_000_000)
+    };
+    let datetime =
+        DateTime::from_rd_seconds(seconds + UNIX_EPOCH, (micros * 1000) as u32, UtcOffset::UTC);
+
+    Timestamp::from_datetime(&datetime, Precision::MICROS)
+}
+
+/// Converts a Unix timestamp into an RFC3339 formatted date-time string in UTC,
+/// checking that the input is in range.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch.
+/// * `micros` - Microseconds part to be included in the timestamp.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `micros` is 1,000,000 or more,
+/// [`Error::Overflow`] if `seconds` does not fit into an `i64` and
+/// [`Error::YearOutOfRange`] if it is after [`MAX_UNIX_SECONDS`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{try_format_unix, Error};
+///
+/// let timestamp = try_format_unix(1609459200, 0).unwrap();
+/// assert_eq!(timestamp, "2021-01-01T00:00:00.000000Z");
+///
+/// assert_eq!(try_format_unix(1609459200, 1_000_000), Err(Error::InvalidSubsecond));
+/// ```
+pub fn try_format_unix(seconds: u64, micros: u32) -> Result<Timestamp, Error> {
+    let seconds = i64::try_from(seconds).map_err(|_| Error::Overflow)?;
+
+    format_unix_i64(seconds, micros)
+}
+
+/// Converts a signed Unix timestamp into an RFC3339 formatted date-time string
+/// in UTC, including times before the Unix Epoch.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `micros` - Microseconds part to be included in the timestamp.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `micros` is 1,000,000 or more and
+/// [`Error::YearOutOfRange`] if `seconds` is outside of [`MIN_UNIX_SECONDS`]
+/// to [`MAX_UNIX_SECONDS`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_unix_i64;
+///
+/// let timestamp = format_unix_i64(-1, 0).unwrap();
+/// assert_eq!(timestamp, "1969-12-31T23:59:59.000000Z");
+/// ```
+pub fn format_unix_i64(seconds: i64, micros: u32) -> Result<Timestamp, Error> {
+    format_unix_with(seconds, micros, FormatOptions::default())
+}
+
+/// Converts a signed Unix timestamp into an RFC3339 formatted date-time string
+/// in local time with the given UTC offset.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `micros` - Microseconds part to be included in the timestamp.
+/// * `offset` - The offset from UTC of the local time.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `micros` is 1,000,000 or more and
+/// [`Error::YearOutOfRange`] if the local time falls outside of the years 0001
+/// to 9999.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_offset, UtcOffset};
+///
+/// let pdt = UtcOffset::from_minutes(-7 * 60).unwrap();
+/// let timestamp = format_unix_offset(1445470140, 0, pdt).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T16:29:00.000000-07:00");
+///
+/// let timestamp = format_unix_offset(1445470140, 0, UtcOffset::UNKNOWN).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.000000-00:00");
+/// ```
+pub fn format_unix_offset(
+    seconds: i64,
+    micros: u32,
+    offset: UtcOffset,
+) -> Result<Timestamp, Error> {
+    let options = FormatOptions {
+        offset,
+        ..FormatOptions::default()
+    };
+
+    format_unix_with(seconds, micros, options)
+}
+
+/// Converts a signed Unix timestamp into an RFC3339 formatted date-time string
+/// with the given formatting options.
+///
+/// Fractional digits beyond the requested precision are truncated.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `micros` - Microseconds part to be included in the timestamp.
+/// * `options` - The UTC offset and fractional second precision to use.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `micros` is 1,000,000 or more,
+/// [`Error::InvalidPrecision`] if a fixed precision is not between 1 and 9
+/// digits and [`Error::YearOutOfRange`] if the local time falls outside of the
+/// years 0001 to 9999.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_with, FormatOptions, Precision};
+///
+/// let options = FormatOptions {
+///     precision: Precision::MILLIS,
+///     ..FormatOptions::default()
+/// };
+/// let timestamp = format_unix_with(1445470140, 123456, options).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.123Z");
+///
+/// let options = FormatOptions {
+///     precision: Precision::Auto,
+///     ..FormatOptions::default()
+/// };
+/// let timestamp = format_unix_with(1445470140, 500000, options).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.5Z");
+/// ```
+pub fn format_unix_with(
+    seconds: i64,
+    micros: u32,
+    options: FormatOptions,
+) -> Result<Timestamp, Error> {
+    if micros >= 1_000_000 {
+        return Err(Error::InvalidSubsecond);
+    }
+
+    format_unix_nanos_with(seconds, micros * 1000, options)
+}
+
+/// Converts a signed Unix timestamp with nanosecond resolution into an RFC3339
+/// formatted date-time string in UTC with nine fractional digits.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `nanos` - Nanoseconds part to be included in the timestamp.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more and
+/// [`Error::YearOutOfRange`] if `seconds` is outside of [`MIN_UNIX_SECONDS`]
+/// to [`MAX_UNIX_SECONDS`].
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_unix_nanos;
+///
+/// let timestamp = format_unix_nanos(1445470140, 123456789).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.123456789Z");
+/// ```
+pub fn format_unix_nanos(seconds: i64, nanos: u32) -> Result<Timestamp, Error> {
+    let options = FormatOptions {
+        precision: Precision::NANOS,
+        ..FormatOptions::default()
+    };
+
+    format_unix_nanos_with(seconds, nanos, options)
+}
+
+/// Converts a signed Unix timestamp with nanosecond resolution into an RFC3339
+/// formatted date-time string with the given formatting options.
+///
+/// When fewer than nine fractional digits are requested the remaining digits
+/// are truncated rather than rounded, so the formatted time is never later
+/// than the instant given and never rolls over into the next second.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `nanos` - Nanoseconds part to be included in the timestamp.
+/// * `options` - The UTC offset and fractional second precision to use.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more,
+/// [`Error::InvalidPrecision`] if a fixed precision is not between 1 and 9
+/// digits and [`Error::YearOutOfRange`] if the local time falls outside of the
+/// years 0001 to 9999.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_nanos_with, FormatOptions, Precision};
+///
+/// let options = FormatOptions {
+///     precision: Precision::MILLIS,
+///     ..FormatOptions::default()
+/// };
+/// let timestamp = format_unix_nanos_with(1445470140, 999999999, options).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.999Z");
+/// ```
+pub fn format_unix_nanos_with(
+    seconds: i64,
+    nanos: u32,
+    options: FormatOptions,
+) -> Result<Timestamp, Error> {
+    DateTime::from_unix_offset(seconds, nanos, options.offset)?.format(options.precision)
+}
+
+/// Converts a signed Unix timestamp into an RFC3339 formatted date-time string
+/// in UTC, written into a caller provided buffer.
+///
+/// The output matches [`format_unix_i64`] and needs 27 bytes.
+///
+/// # Arguments
+///
+/// * `seconds` - The number of seconds since Unix Epoch, negative for earlier times.
+/// * `micros` - Microseconds part to be included in the timestamp.
+/// * `buf` - The buffer to write the timestamp into.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSubsecond`] if `micros` is 1,000,000 or more,
+/// [`Error::YearOutOfRange`] if `seconds` is outside of [`MIN_UNIX_SECONDS`]
+/// to [`MAX_UNIX_SECONDS`] and [`Error::BufferTooSmall`] if the timestamp does
+/// not fit into `buf`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_unix_into;
+///
+/// let mut buf = [0u8; 64];
+/// let timestamp = format_unix_into(1609459200, 0, &mut buf).unwrap();
+/// assert_eq!(timestamp, "2021-01-01T00:00:00.000000Z");
+/// ```
+pub fn format_unix_into(seconds: i64, micros: u32, buf: &mut [u8]) -> Result<&str, Error> {
+    if micros >= 1_000_000 {
+        return Err(Error::InvalidSubsecond);
+    }
+
+    DateTime::from_unix(seconds, micros * 1000)?.format_into(Precision::MICROS, buf)
+}
+
+/// Converts a sequence of signed Unix timestamps with nanosecond resolution
+/// into RFC3339 formatted date-time strings with the given formatting options.
+///
+/// # Arguments
+///
+/// * `times` - Pairs of seconds since Unix Epoch and nanoseconds.
+/// * `options` - The UTC offset and fractional second precision to use.
+///
+/// # Errors
+///
+/// Returns the first error [`format_unix_nanos_with`] returns for any of the
+/// timestamps.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_vec, FormatOptions, Precision};
+///
+/// let options = FormatOptions {
+///     precision: Precision::Seconds,
+///     ..FormatOptions::default()
+/// };
+/// let timestamps = format_unix_vec([(0, 0), (1445470140, 0)], options).unwrap();
+/// assert_eq!(timestamps, ["1970-01-01T00:00:00Z", "2015-10-21T23:29:00Z"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_unix_vec<I>(
+    times: I,
+    options: FormatOptions,
+) -> Result<alloc::vec::Vec<Timestamp>, Error>
+where
+    I: IntoIterator<Item = (i64, u32)>,
+{
+    times
+        .into_iter()
+        .map(|(seconds, nanos)| format_unix_nanos_with(seconds, nanos, options))
+        .collect()
 }
 
 /// Rata Die algorithm by Peter Baum.
@@ -84,7 +495,7 @@ fn rdn_to_ymd(rdn: u64) -> (u32, u32, u32) {
     let a = h / 3652425;
     let b = a - (a >> 2);
     let mut y = (100 * b + h) / 36525;
-    let d = b + z - (1461 * y >> 2);
+    let d = b + z - ((1461 * y) >> 2);
     let mut m = (535 * d + 48950) >> 14;
 
     if m > 12 {
@@ -95,6 +506,25 @@ fn rdn_to_ymd(rdn: u64) -> (u32, u32, u32) {
     (y as u32, m as u32, (d - DAY_OFFSETS[m as usize]) as u32)
 }
 
+/// Inverse of [`rdn_to_ymd`], the year must be at least 1.
+fn ymd_to_rdn(year: u32, month: u32, day: u32) -> u64 {
+    let y = if month < 3 { year - 1 } else { year } as u64;
+
+    day as u64 + DAY_OFFSETS[month as usize] + 365 * y + y / 4 - y / 100 + y / 400 - 306
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -109,5 +539,115 @@ mod tests {
 
         let expected = "2015-10-21T23:29:00.123456Z";
         assert_eq!(result.as_str(), expected);
+
+        let result = format_unix(0, 5_000_001);
+        assert_eq!(result.as_str(), "1970-01-01T00:00:05.000001Z");
+        let result = format_unix(0, u32::MAX);
+        assert_eq!(result.as_str(), "1970-01-01T01:11:34.967295Z");
+
+        let result = format_unix(MAX_UNIX_SECONDS as u64 + 1, 0);
+        assert_eq!(result.as_str(), "9999-12-31T23:59:59.999999Z");
+        let result = format_unix(u64::MAX, u32::MAX);
+        assert_eq!(result.as_str(), "9999-12-31T23:59:59.999999Z");
+    }
+
+    #[test]
+    fn test_format_unix_i64() {
+        let result = format_unix_i64(MIN_UNIX_SECONDS, 0).unwrap();
+        assert_eq!(result.as_str(), "0001-01-01T00:00:00.000000Z");
+
+        let result = format_unix_i64(MAX_UNIX_SECONDS, 999999).unwrap();
+        assert_eq!(result.as_str(), "9999-12-31T23:59:59.999999Z");
+
+        assert_eq!(
+            format_unix_i64(MIN_UNIX_SECONDS - 1, 0),
+            Err(Error::YearOutOfRange)
+        );
+        assert_eq!(
+            format_unix_i64(MAX_UNIX_SECONDS + 1, 0),
+            Err(Error::YearOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_try_format_unix() {
+        let result = try_format_unix(1445470140, 999999).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00.999999Z");
+
+        assert_eq!(
+            try_format_unix(1445470140, 1_000_000),
+            Err(Error::InvalidSubsecond)
+        );
+        assert_eq!(
+            try_format_unix(MAX_UNIX_SECONDS as u64 + 1, 0),
+            Err(Error::YearOutOfRange)
+        );
+        assert_eq!(try_format_unix(u64::MAX, 0), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_format_unix_offset() {
+        let offset = UtcOffset::from_minutes(5 * 60 + 30).unwrap();
+        let result = format_unix_offset(1445470140, 123456, offset).unwrap();
+        assert_eq!(result.as_str(), "2015-10-22T04:59:00.123456+05:30");
+
+        let offset = UtcOffset::from_minutes(-1).unwrap();
+        let result = format_unix_offset(0, 0, offset).unwrap();
+        assert_eq!(result.as_str(), "1969-12-31T23:59:00.000000-00:01");
+
+        let offset = UtcOffset::from_minutes(-60).unwrap();
+        assert_eq!(
+            format_unix_offset(MIN_UNIX_SECONDS, 0, offset),
+            Err(Error::YearOutOfRange)
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_format_unix_with_precision() {
+        let format = |micros, precision| {
+            let options = FormatOptions {
+                precision,
+                ..FormatOptions::default()
+            };
+            format_unix_with(1445470140, micros, options)
+        };
+
+        let result = format(123456, Precision::Seconds).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00Z");
+        let result = format(123456, Precision::Digits(1)).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00.1Z");
+        let result = format(123456, Precision::NANOS).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00.123456000Z");
+        let result = format(120000, Precision::Auto).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00.12Z");
+        let result = format(0, Precision::Auto).unwrap();
+        assert_eq!(result.as_str(), "2015-10-21T23:29:00Z");
+
+        assert_eq!(
+            format(0, Precision::Digits(0)),
+            Err(Error::InvalidPrecision)
+        );
+        assert_eq!(
+            format(0, Precision::Digits(10)),
+            Err(Error::InvalidPrecision)
+        );
+    }
+
+    #[test]
+    fn test_format_unix_nanos() {
+        let result = format_unix_nanos(-1, 1).unwrap();
+        assert_eq!(result.as_str(), "1969-12-31T23:59:59.000000001Z");
+
+        let options = FormatOptions {
+            precision: Precision::Auto,
+            ..FormatOptions::default()
+        };
+        let result = format_unix_nanos_with(0, 10, options).unwrap();
+        assert_eq!(result.as_str(), "1970-01-01T00:00:00.00000001Z");
+
+        assert_eq!(
+            format_unix_nanos(0, 1_000_000_000),
+            Err(Error::InvalidSubsecond)
+        );
+    }
+}
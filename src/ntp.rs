@@ -0,0 +1,304 @@
+//! NTP timestamp formats (RFC 5905).
+//!
+//! NTP counts seconds since 1900-01-01T00:00:00Z in 32 bits, so the count
+//! rolls over into a new era every 2^32 seconds, first in 2036. The era is not
+//! transmitted and has to be inferred from a pivot time known to be within 68
+//! years of the timestamp, such as the build date or the current clock.
+
+use core::time::Duration;
+
+use crate::{DateTime, Error, Precision, Timestamp};
+
+/// Offset between the NTP (1900) and Unix (1970) epochs in seconds.
+pub(crate) const NTP_UNIX_OFFSET: i64 = 2208988800;
+
+const ERA_SECONDS: i64 = 1 << 32;
+
+/// Converts a 32 bit binary fraction of a second into nanoseconds, truncating.
+fn nanos_from_fraction(fraction: u32) -> u32 {
+    ((fraction as u64 * 1_000_000_000) >> 32) as u32
+}
+
+/// Converts nanoseconds into a 32 bit binary fraction of a second, rounding up
+/// so that converting back gives the same nanoseconds.
+fn fraction_from_nanos(nanos: u32) -> u32 {
+    ((nanos as u64) << 32).div_ceil(1_000_000_000) as u32
+}
+
+/// A 64 bit NTP timestamp, 32 bits of seconds since the start of the NTP era
+/// and 32 bits of binary fraction.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::NtpTimestamp;
+///
+/// let ntp = NtpTimestamp::from_be_bytes([0xd9, 0xd2, 0x9e, 0x3c, 0x80, 0, 0, 0]);
+/// assert_eq!(ntp.to_unix(1700000000), Ok((1445470140, 500_000_000)));
+/// assert_eq!(ntp.format(1700000000).unwrap(), "2015-10-21T23:29:00.500000000Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NtpTimestamp(u64);
+
+impl NtpTimestamp {
+    /// Creates a timestamp from its 64 bit representation.
+    pub const fn from_bits(bits: u64) -> Self {
+        NtpTimestamp(bits)
+    }
+
+    /// Returns the 64 bit representation.
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a timestamp from its big-endian wire format.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        NtpTimestamp(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the big-endian wire format.
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a timestamp from seconds within the era and a binary fraction.
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        NtpTimestamp((seconds as u64) << 32 | fraction as u64)
+    }
+
+    /// Returns the seconds within the era.
+    pub const fn seconds(self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns the binary fraction of the second.
+    pub const fn fraction(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Creates a timestamp from Unix seconds and nanoseconds, discarding the
+    /// era.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or more.
+    pub fn from_unix(seconds: i64, nanos: u32) -> Result<NtpTimestamp, Error> {
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidSubsecond);
+        }
+
+        let seconds = seconds
+            .checked_add(NTP_UNIX_OFFSET)
+            .ok_or(Error::Overflow)?
+            .rem_euclid(ERA_SECONDS);
+        Ok(NtpTimestamp::new(
+            seconds as u32,
+            fraction_from_nanos(nanos),
+        ))
+    }
+
+    /// Converts into Unix seconds and nanoseconds in the given NTP era, era 0
+    /// starting in 1900 and era 1 in 2036.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the resulting Unix time does not fit
+    /// into an `i64`, for an `era` close to the limits of an `i32`.
+    pub fn to_unix_era(self, era: i32) -> Result<(i64, u32), Error> {
+        let seconds = (era as i64)
+            .checked_mul(ERA_SECONDS)
+            .and_then(|seconds| seconds.checked_add(self.seconds() as i64 - NTP_UNIX_OFFSET))
+            .ok_or(Error::Overflow)?;
+        Ok((seconds, nanos_from_fraction(self.fraction())))
+    }
+
+    /// Converts into Unix seconds and nanoseconds in the era that places it
+    /// closest to the `pivot` Unix time, i.e. within about 68 years.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the resulting Unix time does not fit
+    /// into an `i64`, for a `pivot` close to its limits.
+    pub fn to_unix(self, pivot: i64) -> Result<(i64, u32), Error> {
+        let (seconds, nanos) = self.to_unix_era(0)?;
+        let (seconds, era_seconds) = (seconds as i128, ERA_SECONDS as i128);
+        let era = (pivot as i128 - seconds + era_seconds / 2).div_euclid(era_seconds);
+        let seconds = i64::try_from(seconds + era * era_seconds).map_err(|_| Error::Overflow)?;
+        Ok((seconds, nanos))
+    }
+
+    /// Formats as an RFC3339 timestamp in UTC with nine fractional digits, in
+    /// the era closest to the `pivot` Unix time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the resulting time is outside of
+    /// the years 0001 to 9999 and [`Error::Overflow`] if it does not fit into
+    /// an `i64`.
+    pub fn format(self, pivot: i64) -> Result<Timestamp, Error> {
+        DateTime::from_ntp(self, pivot)?.format(Precision::NANOS)
+    }
+}
+
+/// A 32 bit NTP short format duration, 16 bits of seconds and 16 bits of
+/// binary fraction, as used for root delay and root dispersion.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+/// use rfc3339::NtpShort;
+///
+/// let delay = NtpShort::from_bits(0x0001_8000);
+/// assert_eq!(delay.to_duration(), Duration::from_millis(1500));
+/// assert_eq!(NtpShort::from_duration(Duration::from_millis(1500)), Ok(delay));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NtpShort(u32);
+
+impl NtpShort {
+    /// Creates a duration from its 32 bit representation.
+    pub const fn from_bits(bits: u32) -> Self {
+        NtpShort(bits)
+    }
+
+    /// Returns the 32 bit representation.
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Creates a duration from its big-endian wire format.
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Self {
+        NtpShort(u32::from_be_bytes(bytes))
+    }
+
+    /// Returns the big-endian wire format.
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a short format duration, rounding up to the next 1/65536th of
+    /// a second.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the duration is 65536 seconds or longer.
+    pub fn from_duration(duration: Duration) -> Result<NtpShort, Error> {
+        if duration.as_secs() > 0xffff {
+            return Err(Error::Overflow);
+        }
+
+        // A fraction rounded up to a whole second still overflows below.
+        let fraction = ((duration.subsec_nanos() as u64) << 16).div_ceil(1_000_000_000);
+        let bits = (duration.as_secs() << 16) + fraction;
+
+        u32::try_from(bits)
+            .map(NtpShort)
+            .map_err(|_| Error::Overflow)
+    }
+
+    /// Converts into a duration, truncating to whole nanoseconds.
+    pub fn to_duration(self) -> Duration {
+        let nanos = ((self.0 & 0xffff) as u64 * 1_000_000_000) >> 16;
+        Duration::new((self.0 >> 16) as u64, nanos as u32)
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from an NTP timestamp, in the era
+    /// closest to the `pivot` Unix time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the resulting time is outside of
+    /// the years 0001 to 9999 and [`Error::Overflow`] if it does not fit into
+    /// an `i64`.
+    pub fn from_ntp(timestamp: NtpTimestamp, pivot: i64) -> Result<DateTime, Error> {
+        let (seconds, nanos) = timestamp.to_unix(pivot)?;
+        DateTime::from_unix(seconds, nanos)
+    }
+
+    /// Converts into an NTP timestamp, discarding the era.
+    pub fn to_ntp(&self) -> NtpTimestamp {
+        let (seconds, nanos) = self.to_unix();
+        NtpTimestamp::from_unix(seconds, nanos).expect("date times are in range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eras() {
+        let ntp = NtpTimestamp::new(0, 0);
+        assert_eq!(ntp.to_unix_era(0), Ok((-NTP_UNIX_OFFSET, 0)));
+        assert_eq!(ntp.to_unix(-NTP_UNIX_OFFSET), Ok((-NTP_UNIX_OFFSET, 0)));
+        // Era 1 starts on 2036-02-07T06:28:16Z, closer to 1970 than 1900 is.
+        assert_eq!(ntp.to_unix_era(1), Ok((2085978496, 0)));
+        assert_eq!(ntp.to_unix(0), Ok((2085978496, 0)));
+
+        let ntp = NtpTimestamp::from_unix(2085978497, 0).unwrap();
+        assert_eq!(ntp.seconds(), 1);
+        assert_eq!(ntp.to_unix(1700000000), Ok((2085978497, 0)));
+    }
+
+    #[test]
+    fn test_fraction_round_trip() {
+        for nanos in [0, 1, 500_000_000, 999_999_999] {
+            let ntp = NtpTimestamp::from_unix(0, nanos).unwrap();
+            assert_eq!(ntp.to_unix(0), Ok((0, nanos)));
+        }
+    }
+
+    #[test]
+    fn test_pivot_limits() {
+        // The closest era lies beyond the limits of an i64.
+        let ntp = NtpTimestamp::new(0, 0);
+        assert_eq!(ntp.to_unix(i64::MAX), Err(Error::Overflow));
+        assert_eq!(ntp.format(i64::MAX), Err(Error::Overflow));
+        let ntp = NtpTimestamp::new(1 << 31, 0);
+        assert_eq!(ntp.to_unix(i64::MIN), Err(Error::Overflow));
+        assert_eq!(DateTime::from_ntp(ntp, i64::MIN), Err(Error::Overflow));
+
+        // Within the limits, but far outside of the supported years.
+        let ntp = NtpTimestamp::new(u32::MAX, 0);
+        assert_eq!(ntp.format(i64::MIN), Err(Error::YearOutOfRange));
+    }
+
+    #[test]
+    fn test_era_limits() {
+        let ntp = NtpTimestamp::new(0, 0);
+        assert_eq!(ntp.to_unix_era(i32::MIN), Err(Error::Overflow));
+        let ntp = NtpTimestamp::new(u32::MAX, 0);
+        assert_eq!(
+            ntp.to_unix_era(i32::MAX),
+            Ok((i64::MAX - NTP_UNIX_OFFSET, 0))
+        );
+    }
+
+    #[test]
+    fn test_short_overflow() {
+        let max = NtpShort::from_bits(u32::MAX).to_duration();
+        assert_eq!(
+            NtpShort::from_duration(max),
+            Ok(NtpShort::from_bits(u32::MAX))
+        );
+        assert_eq!(
+            NtpShort::from_duration(Duration::from_secs(1 << 16)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            NtpShort::from_duration(Duration::from_secs(1 << 48)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            NtpShort::from_duration(Duration::new((1 << 48) - 1, 999_999_999)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            NtpShort::from_duration(Duration::new(0xffff, 999_999_999)),
+            Err(Error::Overflow)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Fixed offsets from UTC.
+
+use core::fmt;
+
+use crate::Error;
+
+const MAX_OFFSET_MINUTES: i16 = 23 * 60 + 59;
+
+/// A fixed offset from UTC in the range -23:59 to +23:59.
+///
+/// Besides numeric offsets RFC3339 defines `-00:00` to mean the time is in UTC
+/// but the offset to local time is unknown, this is available as
+/// [`UtcOffset::UNKNOWN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UtcOffset {
+    minutes: i16,
+    unknown: bool,
+}
+
+impl UtcOffset {
+    /// Coordinated Universal Time, formatted as `Z`.
+    pub const UTC: UtcOffset = UtcOffset {
+        minutes: 0,
+        unknown: false,
+    };
+
+    /// UTC with an unknown local offset, formatted as `-00:00`.
+    pub const UNKNOWN: UtcOffset = UtcOffset {
+        minutes: 0,
+        unknown: true,
+    };
+
+    /// Creates an offset from a number of minutes east of UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOffset`] if `minutes` is outside of -23:59 to
+    /// +23:59.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::UtcOffset;
+    ///
+    /// let pdt = UtcOffset::from_minutes(-7 * 60).unwrap();
+    /// assert_eq!(pdt.seconds(), -25200);
+    /// ```
+    pub const fn from_minutes(minutes: i16) -> Result<UtcOffset, Error> {
+        if minutes < -MAX_OFFSET_MINUTES || minutes > MAX_OFFSET_MINUTES {
+            return Err(Error::InvalidOffset);
+        }
+
+        Ok(UtcOffset {
+            minutes,
+            unknown: false,
+        })
+    }
+
+    /// Creates an offset from a number of seconds east of UTC, truncating to
+    /// whole minutes as RFC3339 offsets have no seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOffset`] if `seconds` is outside of -23:59 to
+    /// +23:59.
+    pub const fn from_seconds(seconds: i32) -> Result<UtcOffset, Error> {
+        let minutes = seconds / 60;
+        if minutes < -MAX_OFFSET_MINUTES as i32 || minutes > MAX_OFFSET_MINUTES as i32 {
+            return Err(Error::InvalidOffset);
+        }
+
+        UtcOffset::from_minutes(minutes as i16)
+    }
+
+    /// Returns the offset in minutes east of UTC.
+    pub const fn minutes(self) -> i16 {
+        self.minutes
+    }
+
+    /// Returns the offset in seconds east of UTC.
+    pub const fn seconds(self) -> i32 {
+        self.minutes as i32 * 60
+    }
+
+    /// Returns true if this is the `-00:00` unknown local offset.
+    pub const fn is_unknown(self) -> bool {
+        self.unknown
+    }
+}
+
+impl Default for UtcOffset {
+    fn default() -> Self {
+        UtcOffset::UTC
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.unknown {
+            return f.write_str("-00:00");
+        }
+        if self.minutes == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_minutes() {
+        assert_eq!(UtcOffset::from_minutes(0), Ok(UtcOffset::UTC));
+        assert!(UtcOffset::from_minutes(1439).is_ok());
+        assert!(UtcOffset::from_minutes(-1439).is_ok());
+        assert_eq!(UtcOffset::from_minutes(1440), Err(Error::InvalidOffset));
+        assert_eq!(UtcOffset::from_minutes(-1440), Err(Error::InvalidOffset));
+    }
+}
@@ -0,0 +1,348 @@
+//! Parsing of RFC3339 timestamps back into Unix time.
+
+use core::fmt;
+
+use crate::{days_in_month, DateTime, UtcOffset};
+
+/// An error returned when an RFC3339 timestamp could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input ended before a complete timestamp was read.
+    UnexpectedEnd,
+    /// The byte at the given offset does not match the RFC3339 grammar.
+    InvalidCharacter(usize),
+    /// The year is outside the supported range of 0001 to 9999.
+    InvalidYear,
+    /// The month is not between 01 and 12.
+    InvalidMonth,
+    /// The day does not exist in the given month.
+    InvalidDay,
+    /// The hour is not between 00 and 23.
+    InvalidHour,
+    /// The minute is not between 00 and 59.
+    InvalidMinute,
+    /// The second is not between 00 and 60.
+    InvalidSecond,
+    /// The fractional second has more than nine digits.
+    InvalidFraction,
+    /// The UTC offset is not between -23:59 and +23:59.
+    InvalidOffset,
+    /// Input remains after a complete timestamp.
+    TrailingCharacters,
+    /// A leap second was given but is not allowed.
+    LeapSecond,
+    /// A critical annotation is inconsistent with the timestamp or another
+    /// annotation.
+    InconsistentAnnotation,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedEnd => f.write_str("unexpected end of input"),
+            ParseError::InvalidCharacter(pos) => write!(f, "invalid character at offset {}", pos),
+            ParseError::InvalidYear => f.write_str("year out of range"),
+            ParseError::InvalidMonth => f.write_str("month out of range"),
+            ParseError::InvalidDay => f.write_str("day out of range"),
+            ParseError::InvalidHour => f.write_str("hour out of range"),
+            ParseError::InvalidMinute => f.write_str("minute out of range"),
+            ParseError::InvalidSecond => f.write_str("second out of range"),
+            ParseError::InvalidFraction => f.write_str("too many fractional digits"),
+            ParseError::InvalidOffset => f.write_str("utc offset out of range"),
+            ParseError::TrailingCharacters => f.write_str("trailing characters"),
+            ParseError::LeapSecond => f.write_str("leap second not allowed"),
+            ParseError::InconsistentAnnotation => f.write_str("inconsistent critical annotation"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Abbreviated day names, starting with Sunday.
+pub(crate) const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Abbreviated month names, starting with January.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A simple cursor over the bytes of the input.
+pub(crate) struct Cursor<'a> {
+    pub(crate) input: &'a [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    pub(crate) fn next(&mut self) -> Result<u8, ParseError> {
+        let byte = self.peek().ok_or(ParseError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    /// Consumes a byte matching any of the given (ASCII case sensitive) bytes.
+    pub(crate) fn expect(&mut self, any: &[u8]) -> Result<u8, ParseError> {
+        let byte = self.next()?;
+        if any.contains(&byte) {
+            Ok(byte)
+        } else {
+            Err(ParseError::InvalidCharacter(self.pos - 1))
+        }
+    }
+
+    /// Consumes exactly `count` decimal digits.
+    pub(crate) fn digits(&mut self, count: usize) -> Result<u32, ParseError> {
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self.next()?;
+            if !byte.is_ascii_digit() {
+                return Err(ParseError::InvalidCharacter(self.pos - 1));
+            }
+            value = value * 10 + (byte - b'0') as u32;
+        }
+        Ok(value)
+    }
+
+    /// Consumes one to nine fractional digits, returning them as nanoseconds.
+    pub(crate) fn fraction(&mut self) -> Result<u32, ParseError> {
+        let mut nanos = 0;
+        let mut count = 0;
+        while let Some(byte @ b'0'..=b'9') = self.peek() {
+            if count == 9 {
+                return Err(ParseError::InvalidFraction);
+            }
+            nanos = nanos * 10 + (byte - b'0') as u32;
+            count += 1;
+            self.pos += 1;
+        }
+        if count == 0 {
+            return Err(match self.peek() {
+                Some(_) => ParseError::InvalidCharacter(self.pos),
+                None => ParseError::UnexpectedEnd,
+            });
+        }
+        Ok(nanos * 10u32.pow(9 - count))
+    }
+
+    /// Consumes the hours and minutes of a numeric offset after its `sign`,
+    /// with the `separator` between them if any. The minutes may be left out
+    /// if `reduced`.
+    pub(crate) fn offset(
+        &mut self,
+        sign: u8,
+        separator: Option<u8>,
+        reduced: bool,
+    ) -> Result<UtcOffset, ParseError> {
+        let hours = self.digits(2)?;
+        let present = match separator {
+            Some(separator) => self.peek() == Some(separator),
+            None => self.peek().is_some_and(|byte| byte.is_ascii_digit()),
+        };
+
+        let mut minutes = 0;
+        if present || !reduced {
+            if let Some(separator) = separator {
+                self.expect(&[separator])?;
+            }
+            minutes = self.digits(2)?;
+        }
+        if hours > 23 || minutes > 59 {
+            return Err(ParseError::InvalidOffset);
+        }
+
+        let minutes = (hours * 60 + minutes) as i16;
+        Ok(match (sign, minutes) {
+            (b'-', 0) => UtcOffset::UNKNOWN,
+            (b'-', _) => UtcOffset::from_minutes(-minutes).unwrap(),
+            _ => UtcOffset::from_minutes(minutes).unwrap(),
+        })
+    }
+
+    /// Consumes one of `names`, returning its index.
+    pub(crate) fn name(&mut self, names: &[&str]) -> Result<usize, ParseError> {
+        let rest = &self.input[self.pos..];
+        let index = names
+            .iter()
+            .position(|name| rest.starts_with(name.as_bytes()))
+            .ok_or(match rest.is_empty() {
+                true => ParseError::UnexpectedEnd,
+                false => ParseError::InvalidCharacter(self.pos),
+            })?;
+
+        self.pos += names[index].len();
+        Ok(index)
+    }
+}
+
+/// Parses an RFC3339 `date-time` into Unix seconds and nanoseconds in UTC.
+///
+/// The full RFC3339 grammar is accepted: `T`, `t` or a space as the date/time
+/// separator, one to nine fractional second digits, and either `Z`, `z` or a
+/// numeric offset which is applied to normalize the result to UTC. The
+/// "unknown local offset" `-00:00` is treated as UTC.
+///
+/// A leap second (`:60`) is accepted and folded into the following second, as
+/// Unix time has no representation for it. Use
+/// [`parse_with_leap_seconds`](crate::parse_with_leap_seconds) to validate or
+/// reject leap seconds.
+///
+/// Only the RFC3339 profile of ISO 8601 is accepted. Use
+/// [`IsoDateTime`](crate::IsoDateTime) for the basic format, reduced
+/// precision and decimal fractions of hours and minutes.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::parse;
+///
+/// assert_eq!(parse("2015-10-21T16:29:00-07:00"), Ok((1445470140, 0)));
+/// assert_eq!(parse("1969-12-31t23:59:59.25z"), Ok((-1, 250_000_000)));
+/// ```
+pub fn parse(input: &str) -> Result<(i64, u32), ParseError> {
+    parse_datetime(input).map(|datetime| datetime.to_unix())
+}
+
+/// Parses an RFC3339 `date-time`, keeping the local time and offset.
+pub(crate) fn parse_datetime(input: &str) -> Result<DateTime, ParseError> {
+    parse_datetime_prefix(input, b"").map(|(datetime, _)| datetime)
+}
+
+/// Parses an RFC3339 `date-time` at the start of `input`, which may be
+/// followed by anything starting with one of the `terminators`, returning it
+/// and its length.
+pub(crate) fn parse_datetime_prefix(
+    input: &str,
+    terminators: &[u8],
+) -> Result<(DateTime, usize), ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    let year = cursor.digits(4)?;
+    cursor.expect(b"-")?;
+    let month = cursor.digits(2)?;
+    cursor.expect(b"-")?;
+    let day = cursor.digits(2)?;
+    cursor.expect(b"Tt ")?;
+    let hour = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let minute = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let second = cursor.digits(2)?;
+
+    let mut nanos = 0;
+    if cursor.peek() == Some(b'.') {
+        cursor.pos += 1;
+        nanos = cursor.fraction()?;
+    }
+
+    let offset = match cursor.expect(b"Zz+-")? {
+        sign @ (b'+' | b'-') => cursor.offset(sign, Some(b':'), false)?,
+        _ => UtcOffset::UTC,
+    };
+
+    if cursor
+        .peek()
+        .is_some_and(|byte| !terminators.contains(&byte))
+    {
+        return Err(ParseError::TrailingCharacters);
+    }
+
+    check_date(year, month, day)?;
+    check_time(hour, minute, second)?;
+
+    let datetime = DateTime {
+        year: year as u16,
+        month: month as u8,
+        day: day as u8,
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        nanosecond: nanos,
+        offset,
+    };
+    Ok((datetime, cursor.pos))
+}
+
+/// Checks that a year from 0001 to 9999, month and day form a valid date.
+pub(crate) fn check_date(year: u32, month: u32, day: u32) -> Result<(), ParseError> {
+    if year == 0 {
+        return Err(ParseError::InvalidYear);
+    }
+    if !(1..=12).contains(&month) {
+        return Err(ParseError::InvalidMonth);
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(ParseError::InvalidDay);
+    }
+    Ok(())
+}
+
+/// Checks the ranges of a time of day, allowing a leap second.
+pub(crate) fn check_time(hour: u32, minute: u32, second: u32) -> Result<(), ParseError> {
+    if hour > 23 {
+        return Err(ParseError::InvalidHour);
+    }
+    if minute > 59 {
+        return Err(ParseError::InvalidMinute);
+    }
+    if second > 60 {
+        return Err(ParseError::InvalidSecond);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("2015-10-21T23:29:00.123456Z"),
+            Ok((1445470140, 123456000))
+        );
+        assert_eq!(parse("2015-10-21 16:29:00-07:00"), Ok((1445470140, 0)));
+        assert_eq!(parse("0001-01-01T00:00:00Z"), Ok((-62135596800, 0)));
+        assert_eq!(
+            parse("9999-12-31T23:59:59.999999999Z"),
+            Ok((253402300799, 999999999))
+        );
+        assert_eq!(parse("2016-12-31T23:59:60Z"), Ok((1483228800, 0)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(parse("2015-10-21"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(
+            parse("2015-10-21X23:29:00Z"),
+            Err(ParseError::InvalidCharacter(10))
+        );
+        assert_eq!(parse("2015-02-29T00:00:00Z"), Err(ParseError::InvalidDay));
+        assert_eq!(
+            parse("2015-10-21T23:29:00.Z"),
+            Err(ParseError::InvalidCharacter(20))
+        );
+        assert_eq!(
+            parse("2015-10-21T23:29:00.1234567890Z"),
+            Err(ParseError::InvalidFraction)
+        );
+        assert_eq!(
+            parse("2015-10-21T23:29:00+24:00"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            parse("2015-10-21T23:29:00Z "),
+            Err(ParseError::TrailingCharacters)
+        );
+    }
+}
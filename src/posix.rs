@@ -0,0 +1,445 @@
+//! POSIX TZ strings such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+//!
+//! The format is `std offset [dst [offset] [,start[/time],end[/time]]]`.
+//! Offsets are given west of UTC, so `CET-1` is one hour ahead of UTC. Rule
+//! dates are one of:
+//!
+//! - `Jn`, the day of the year from 1 to 365, never counting February 29.
+//! - `n`, the zero-based day of the year from 0 to 365.
+//! - `Mm.w.d`, day `d` (0 is Sunday) of week `w` of month `m`, where week 5
+//!   is the last such day in the month.
+//!
+//! Transition times default to `02:00:00` and may range from -167 to 167
+//! hours, as allowed by RFC 8536.
+
+use core::fmt;
+
+use crate::{
+    days_in_month, is_leap_year, rdn_to_ymd, ymd_to_rdn, Error, LocalTimeType, TimeZone, UtcOffset,
+    MAX_UNIX_SECONDS, MIN_UNIX_SECONDS, SECONDS_PER_DAY, UNIX_EPOCH,
+};
+
+/// Rata Die day number of 1970-01-01.
+const UNIX_EPOCH_DAY: i64 = (UNIX_EPOCH / SECONDS_PER_DAY) as i64;
+
+/// Rules used when a daylight saving time zone has none, those of the US.
+const DEFAULT_RULES: (Rule, Rule) = (
+    Rule {
+        date: RuleDate::MonthWeekDay(3, 2, 0),
+        time: 7200,
+    },
+    Rule {
+        date: RuleDate::MonthWeekDay(11, 1, 0),
+        time: 7200,
+    },
+);
+
+/// An error returned when a POSIX TZ string could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PosixTzError {
+    /// The input ended early.
+    UnexpectedEnd,
+    /// A time zone abbreviation is malformed.
+    InvalidAbbreviation,
+    /// An offset from UTC is malformed or out of range.
+    InvalidOffset,
+    /// A transition rule is malformed or out of range.
+    InvalidRule,
+    /// There are characters after the end of the TZ string.
+    TrailingCharacters,
+}
+
+impl fmt::Display for PosixTzError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PosixTzError::UnexpectedEnd => f.write_str("unexpected end of tz string"),
+            PosixTzError::InvalidAbbreviation => f.write_str("invalid time zone abbreviation"),
+            PosixTzError::InvalidOffset => f.write_str("invalid utc offset"),
+            PosixTzError::InvalidRule => f.write_str("invalid transition rule"),
+            PosixTzError::TrailingCharacters => f.write_str("trailing characters"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PosixTzError {}
+
+/// The day of the year a transition happens on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDate {
+    /// `Jn`, 1 to 365 without February 29.
+    Julian(u16),
+    /// `n`, 0 to 365.
+    Zero(u16),
+    /// `Mm.w.d`, month, week and weekday.
+    MonthWeekDay(u8, u8, u8),
+}
+
+/// A transition date and local time of day in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Rule {
+    date: RuleDate,
+    time: i32,
+}
+
+impl Rule {
+    /// Returns the Unix time of the transition in `year`, given the offset of
+    /// local time before it.
+    fn unix(&self, year: u32, offset: i32) -> i64 {
+        let rdn = match self.date {
+            RuleDate::Julian(day) => {
+                let leap_day = is_leap_year(year) && day >= 60;
+                ymd_to_rdn(year, 1, 1) + day as u64 - 1 + leap_day as u64
+            }
+            RuleDate::Zero(day) => ymd_to_rdn(year, 1, 1) + day as u64,
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = ymd_to_rdn(year, month as u32, 1);
+                // Rata Die day 1 is a Monday, so day 0 of the week is Sunday.
+                let mut day = (weekday as u64 + 7 - first % 7) % 7 + (week as u64 - 1) * 7;
+                if day >= days_in_month(year, month as u32) as u64 {
+                    day -= 7;
+                }
+                first + day
+            }
+        };
+
+        (rdn as i64 - UNIX_EPOCH_DAY) * SECONDS_PER_DAY as i64 + self.time as i64 - offset as i64
+    }
+}
+
+/// Daylight saving time and when it is in effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Dst<'a> {
+    abbreviation: &'a str,
+    offset: i32,
+    start: Rule,
+    end: Rule,
+}
+
+/// A time zone given as a POSIX TZ string, as in the `TZ` environment
+/// variable or the footer of TZif files.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_zone, PosixTz};
+///
+/// let berlin = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let timestamp = format_unix_zone(1445470140, 0, &berlin).unwrap();
+/// assert_eq!(timestamp, "2015-10-22T01:29:00.000000+02:00");
+/// assert_eq!(berlin.local_time_type(1445470140).abbreviation(), "CEST");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixTz<'a> {
+    abbreviation: &'a str,
+    offset: i32,
+    dst: Option<Dst<'a>>,
+}
+
+impl<'a> PosixTz<'a> {
+    /// Parses a POSIX TZ string.
+    ///
+    /// A daylight saving time zone without rules uses those of the US,
+    /// `M3.2.0,M11.1.0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PosixTzError`] describing the first problem found.
+    pub fn parse(input: &'a str) -> Result<PosixTz<'a>, PosixTzError> {
+        let mut parser = Parser { input, pos: 0 };
+
+        let abbreviation = parser.abbreviation()?;
+        let offset = -parser.time(24, PosixTzError::InvalidOffset)?;
+        if parser.peek().is_none() {
+            return Ok(PosixTz {
+                abbreviation,
+                offset,
+                dst: None,
+            });
+        }
+
+        let dst_abbreviation = parser.abbreviation()?;
+        let dst_offset = match parser.peek() {
+            None | Some(b',') => offset + 3600,
+            Some(_) => -parser.time(24, PosixTzError::InvalidOffset)?,
+        };
+        let (start, end) = match parser.next() {
+            None => DEFAULT_RULES,
+            Some(b',') => {
+                let start = parser.rule()?;
+                if parser.next() != Some(b',') {
+                    return Err(PosixTzError::InvalidRule);
+                }
+                (start, parser.rule()?)
+            }
+            Some(_) => return Err(PosixTzError::InvalidRule),
+        };
+
+        if parser.peek().is_some() {
+            return Err(PosixTzError::TrailingCharacters);
+        }
+
+        Ok(PosixTz {
+            abbreviation,
+            offset,
+            dst: Some(Dst {
+                abbreviation: dst_abbreviation,
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Returns the local time type in effect at the given Unix time.
+    pub fn local_time_type(&self, seconds: i64) -> LocalTimeType<'a> {
+        let standard = LocalTimeType {
+            offset: self.offset,
+            is_dst: false,
+            abbreviation: self.abbreviation,
+        };
+        let Some(dst) = self.dst else {
+            return standard;
+        };
+
+        // Offsets of up to 25 hours may move the local time just outside of
+        // the years 0001 to 9999, which the rules are evaluated in.
+        let clamped = seconds.clamp(
+            MIN_UNIX_SECONDS + SECONDS_PER_DAY as i64,
+            MAX_UNIX_SECONDS - SECONDS_PER_DAY as i64,
+        );
+        let local = (clamped + self.offset as i64 + UNIX_EPOCH as i64) as u64;
+        let (year, _, _) = rdn_to_ymd(local / SECONDS_PER_DAY);
+        let year = year.clamp(1, 9999);
+
+        let start = dst.start.unix(year, self.offset);
+        let end = dst.end.unix(year, dst.offset);
+        let is_dst = if start < end {
+            start <= seconds && seconds < end
+        } else {
+            seconds < end || start <= seconds
+        };
+
+        if is_dst {
+            LocalTimeType {
+                offset: dst.offset,
+                is_dst: true,
+                abbreviation: dst.abbreviation,
+            }
+        } else {
+            standard
+        }
+    }
+}
+
+impl TimeZone for PosixTz<'_> {
+    /// Returns the offset of the local time type in effect, truncated to whole
+    /// minutes.
+    fn utc_offset(&self, seconds: i64) -> Result<UtcOffset, Error> {
+        UtcOffset::from_seconds(self.local_time_type(seconds).offset_seconds())
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    /// Reads an abbreviation, either alphabetic or quoted in `<>`.
+    fn abbreviation(&mut self) -> Result<&'a str, PosixTzError> {
+        let quoted = self.peek() == Some(b'<');
+        if quoted {
+            self.pos += 1;
+        }
+
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            let valid = match quoted {
+                true => b.is_ascii_alphanumeric() || b == b'+' || b == b'-',
+                false => b.is_ascii_alphabetic(),
+            };
+            if !valid {
+                break;
+            }
+            self.pos += 1;
+        }
+        let abbreviation = &self.input[start..self.pos];
+
+        if quoted && self.next() != Some(b'>') {
+            return Err(PosixTzError::InvalidAbbreviation);
+        }
+        if abbreviation.len() < 3 {
+            return Err(PosixTzError::InvalidAbbreviation);
+        }
+        Ok(abbreviation)
+    }
+
+    /// Reads up to `max_digits` decimal digits.
+    fn number(&mut self, max_digits: usize) -> Option<u32> {
+        let start = self.pos;
+        let mut value = 0;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            if self.pos - start == max_digits {
+                return None;
+            }
+            value = value * 10 + (digit - b'0') as u32;
+            self.pos += 1;
+        }
+
+        (self.pos > start).then_some(value)
+    }
+
+    /// Reads `[+-]hh[:mm[:ss]]` in seconds, with at most `max_hours` hours.
+    fn time(&mut self, max_hours: u32, error: PosixTzError) -> Result<i32, PosixTzError> {
+        let sign = match self.peek() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            None => return Err(PosixTzError::UnexpectedEnd),
+            Some(_) => 0,
+        };
+        if sign != 0 {
+            self.pos += 1;
+        }
+
+        let hours = self.number(3).filter(|&h| h <= max_hours).ok_or(error)?;
+        let mut seconds = hours * 3600;
+        for unit in [60, 1] {
+            if self.peek() != Some(b':') {
+                break;
+            }
+            self.pos += 1;
+            let value = self.number(2).filter(|&v| v < 60).ok_or(error)?;
+            seconds += value * unit;
+        }
+
+        Ok(if sign < 0 {
+            -(seconds as i32)
+        } else {
+            seconds as i32
+        })
+    }
+
+    /// Reads a transition rule `date[/time]`.
+    fn rule(&mut self) -> Result<Rule, PosixTzError> {
+        let invalid = PosixTzError::InvalidRule;
+
+        let date = match self.peek() {
+            Some(b'J') => {
+                self.pos += 1;
+                let day = self.number(3).filter(|d| (1..=365).contains(d));
+                RuleDate::Julian(day.ok_or(invalid)? as u16)
+            }
+            Some(b'M') => {
+                self.pos += 1;
+                let month = self.number(2).filter(|m| (1..=12).contains(m));
+                let month = month.ok_or(invalid)?;
+                if self.next() != Some(b'.') {
+                    return Err(invalid);
+                }
+                let week = self.number(1).filter(|w| (1..=5).contains(w));
+                let week = week.ok_or(invalid)?;
+                if self.next() != Some(b'.') {
+                    return Err(invalid);
+                }
+                let weekday = self.number(1).filter(|&d| d <= 6).ok_or(invalid)?;
+                RuleDate::MonthWeekDay(month as u8, week as u8, weekday as u8)
+            }
+            None => return Err(PosixTzError::UnexpectedEnd),
+            Some(_) => RuleDate::Zero(self.number(3).filter(|&d| d <= 365).ok_or(invalid)? as u16),
+        };
+
+        let time = match self.peek() {
+            Some(b'/') => {
+                self.pos += 1;
+                self.time(167, invalid)?
+            }
+            _ => 7200,
+        };
+
+        Ok(Rule { date, time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let tz = PosixTz::parse("<+0330>-3:30").unwrap();
+        assert_eq!(tz.local_time_type(0).offset_seconds(), 12600);
+        assert_eq!(tz.local_time_type(0).abbreviation(), "+0330");
+
+        let tz = PosixTz::parse("EST5EDT").unwrap();
+        assert_eq!(tz.dst.unwrap().offset, -4 * 3600);
+        assert_eq!((tz.dst.unwrap().start, tz.dst.unwrap().end), DEFAULT_RULES);
+
+        for (input, err) in [
+            ("", PosixTzError::InvalidAbbreviation),
+            ("CET", PosixTzError::UnexpectedEnd),
+            ("CET-25", PosixTzError::InvalidOffset),
+            ("CET-1CEST,M3.5.0", PosixTzError::InvalidRule),
+            ("CET-1CEST,M13.5.0,M10.5.0", PosixTzError::InvalidRule),
+            ("CET-1CEST,J0,M10.5.0", PosixTzError::InvalidRule),
+            (
+                "CET-1CEST,M3.5.0,M10.5.0/3 ",
+                PosixTzError::TrailingCharacters,
+            ),
+        ] {
+            assert_eq!(PosixTz::parse(input), Err(err), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        // Last Sunday in March and October 2015 are the 29th and the 25th.
+        let berlin = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert!(!berlin.local_time_type(1427590799).is_dst());
+        assert!(berlin.local_time_type(1427590800).is_dst());
+        assert!(berlin.local_time_type(1445734799).is_dst());
+        assert!(!berlin.local_time_type(1445734800).is_dst());
+
+        // Southern hemisphere, daylight saving time spans the new year.
+        let sydney = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert!(sydney.local_time_type(1451606400).is_dst());
+        assert!(!sydney.local_time_type(1467331200).is_dst());
+
+        // Julian and zero-based days, March 1 in a leap year.
+        let tz = PosixTz::parse("AAA0BBB,J60/0,300").unwrap();
+        assert!(tz.local_time_type(1456790400).is_dst());
+        assert!(!tz.local_time_type(1456790399).is_dst());
+        let tz = PosixTz::parse("AAA0BBB,59/0,300").unwrap();
+        assert!(tz.local_time_type(1456704000).is_dst());
+
+        // Daylight saving time all year.
+        let tz = PosixTz::parse("EST5EDT,0/0,J365/25").unwrap();
+        assert!(tz.local_time_type(1451606400).is_dst());
+        assert!(tz.local_time_type(1483228799).is_dst());
+    }
+
+    #[test]
+    fn test_range_limits() {
+        // Offsets of more than a day west and east of UTC.
+        for input in [
+            "AAA24:59:59BBB,M3.5.0,M10.5.0",
+            "AAA-24:59:59BBB,M3.5.0,M10.5.0",
+        ] {
+            let tz = PosixTz::parse(input).unwrap();
+            for seconds in [i64::MIN, MIN_UNIX_SECONDS, MAX_UNIX_SECONDS, i64::MAX] {
+                assert!(!tz.local_time_type(seconds).is_dst(), "{}", input);
+                assert!(crate::format_unix_zone(seconds, 0, &tz).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Fractional second precision.
+
+use core::fmt;
+
+use crate::Error;
+
+/// The number of fractional second digits written by the formatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// No fractional seconds, e.g. `23:29:00Z`.
+    Seconds,
+    /// A fixed number of fractional digits from 1 to 9, e.g. `23:29:00.120Z`
+    /// for three digits.
+    Digits(u8),
+    /// As many digits as needed with trailing zeros trimmed, omitting the
+    /// fraction entirely when it is zero, like Go's `RFC3339Nano`.
+    Auto,
+}
+
+impl Precision {
+    /// Three fractional digits.
+    pub const MILLIS: Precision = Precision::Digits(3);
+    /// Six fractional digits.
+    pub const MICROS: Precision = Precision::Digits(6);
+    /// Nine fractional digits.
+    pub const NANOS: Precision = Precision::Digits(9);
+
+    /// Checks that a fixed number of digits is between 1 and 9.
+    pub(crate) fn validate(self) -> Result<(), Error> {
+        match self {
+            Precision::Digits(1..=9) | Precision::Seconds | Precision::Auto => Ok(()),
+            Precision::Digits(_) => Err(Error::InvalidPrecision),
+        }
+    }
+
+    /// Writes the fractional part (including the leading `.`) of `nanos`,
+    /// truncating any digits beyond the precision.
+    pub(crate) fn write_fraction<W: fmt::Write>(self, w: &mut W, nanos: u32) -> fmt::Result {
+        let (value, digits) = match self {
+            Precision::Seconds => return Ok(()),
+            Precision::Digits(digits) => (nanos / 10u32.pow(9 - digits as u32), digits),
+            Precision::Auto if nanos == 0 => return Ok(()),
+            Precision::Auto => {
+                let (mut value, mut digits) = (nanos, 9);
+                while value % 10 == 0 {
+                    value /= 10;
+                    digits -= 1;
+                }
+                (value, digits)
+            }
+        };
+
+        write!(w, ".{:0width$}", value, width = digits as usize)
+    }
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision::MICROS
+    }
+}
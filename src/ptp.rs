@@ -0,0 +1,178 @@
+//! PTP (IEEE 1588) timestamps.
+//!
+//! PTP counts TAI seconds since 1970-01-01T00:00:00 TAI. The difference to
+//! UTC is distributed as `currentUtcOffset` in announce messages, 37 seconds
+//! since 2017. Converting with a fixed offset cannot represent the leap second
+//! itself, use [`DateTime::from_tai`] with a [`LeapSeconds`](crate::LeapSeconds)
+//! table for that.
+
+use crate::{DateTime, Error, Precision, Timestamp};
+
+const MAX_SECONDS: u64 = (1 << 48) - 1;
+
+/// A PTP timestamp, 48 bits of TAI seconds and 32 bits of nanoseconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::PtpTimestamp;
+///
+/// let bytes = [0x00, 0x00, 0x56, 0x28, 0x1f, 0xe0, 0x1d, 0xcd, 0x65, 0x00];
+/// let ptp = PtpTimestamp::from_be_bytes(bytes).unwrap();
+/// assert_eq!(ptp.format(36).unwrap(), "2015-10-21T23:29:00.500000000Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PtpTimestamp {
+    seconds: u64,
+    nanos: u32,
+}
+
+impl PtpTimestamp {
+    /// Creates a timestamp from TAI seconds and nanoseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPtpTime`] if `seconds` does not fit into 48
+    /// bits and [`Error::InvalidSubsecond`] if `nanos` is 1,000,000,000 or
+    /// more.
+    pub fn new(seconds: u64, nanos: u32) -> Result<PtpTimestamp, Error> {
+        if seconds > MAX_SECONDS {
+            return Err(Error::InvalidPtpTime);
+        }
+        if nanos >= 1_000_000_000 {
+            return Err(Error::InvalidSubsecond);
+        }
+
+        Ok(PtpTimestamp { seconds, nanos })
+    }
+
+    /// Creates a timestamp from its 10 byte big-endian wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSubsecond`] if the nanoseconds field is
+    /// 1,000,000,000 or more.
+    pub fn from_be_bytes(bytes: [u8; 10]) -> Result<PtpTimestamp, Error> {
+        let [s0, s1, s2, s3, s4, s5, n0, n1, n2, n3] = bytes;
+        let seconds = u64::from_be_bytes([0, 0, s0, s1, s2, s3, s4, s5]);
+
+        PtpTimestamp::new(seconds, u32::from_be_bytes([n0, n1, n2, n3]))
+    }
+
+    /// Returns the 10 byte big-endian wire format.
+    pub fn to_be_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0; 10];
+        bytes[..6].copy_from_slice(&self.seconds.to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&self.nanos.to_be_bytes());
+        bytes
+    }
+
+    /// Returns the TAI seconds.
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Returns the nanoseconds into the second.
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Creates a timestamp from Unix seconds and nanoseconds, adding
+    /// `utc_offset`, the PTP `currentUtcOffset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPtpTime`] if the time is before the PTP epoch
+    /// and any error [`PtpTimestamp::new`] returns.
+    pub fn from_unix(seconds: i64, nanos: u32, utc_offset: i16) -> Result<PtpTimestamp, Error> {
+        let tai = seconds
+            .checked_add(utc_offset as i64)
+            .ok_or(Error::Overflow)?;
+
+        PtpTimestamp::new(
+            u64::try_from(tai).map_err(|_| Error::InvalidPtpTime)?,
+            nanos,
+        )
+    }
+
+    /// Converts into Unix seconds and nanoseconds by subtracting
+    /// `utc_offset`, the PTP `currentUtcOffset`.
+    pub fn to_unix(&self, utc_offset: i16) -> (i64, u32) {
+        (self.seconds as i64 - utc_offset as i64, self.nanos)
+    }
+
+    /// Formats as an RFC3339 timestamp in UTC with nine fractional digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the resulting time is after the
+    /// year 9999.
+    pub fn format(&self, utc_offset: i16) -> Result<Timestamp, Error> {
+        DateTime::from_ptp(self, utc_offset)?.format(Precision::NANOS)
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from a PTP timestamp and the PTP
+    /// `currentUtcOffset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the resulting time is after the
+    /// year 9999.
+    pub fn from_ptp(timestamp: &PtpTimestamp, utc_offset: i16) -> Result<DateTime, Error> {
+        let (seconds, nanos) = timestamp.to_unix(utc_offset);
+        DateTime::from_unix(seconds, nanos)
+    }
+
+    /// Converts into a PTP timestamp with the PTP `currentUtcOffset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPtpTime`] if the time is before the PTP epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::DateTime;
+    ///
+    /// let datetime: DateTime = "2017-01-01T00:00:00Z".parse().unwrap();
+    /// let ptp = datetime.to_ptp(37).unwrap();
+    /// assert_eq!(ptp.seconds(), 1483228837);
+    /// ```
+    pub fn to_ptp(&self, utc_offset: i16) -> Result<PtpTimestamp, Error> {
+        let (seconds, nanos) = self.to_unix();
+        PtpTimestamp::from_unix(seconds, nanos, utc_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wire_format() {
+        let ptp = PtpTimestamp::new(0x0102_0304_0506, 0x0708_090a).unwrap();
+        let bytes = ptp.to_be_bytes();
+        assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(PtpTimestamp::from_be_bytes(bytes), Ok(ptp));
+
+        let bytes = [0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0x00];
+        assert_eq!(
+            PtpTimestamp::from_be_bytes(bytes),
+            Err(Error::InvalidSubsecond)
+        );
+        assert_eq!(PtpTimestamp::new(1 << 48, 0), Err(Error::InvalidPtpTime));
+    }
+
+    #[test]
+    fn test_unix() {
+        let ptp = PtpTimestamp::from_unix(1445470140, 500_000_000, 36).unwrap();
+        assert_eq!(ptp.seconds(), 1445470176);
+        assert_eq!(ptp.to_unix(36), (1445470140, 500_000_000));
+        assert_eq!(
+            PtpTimestamp::from_unix(-100, 0, 37),
+            Err(Error::InvalidPtpTime)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Serde support for RFC3339 timestamps.
+//!
+//! [`Timestamp`] and [`DateTime`] serialize as RFC3339 strings. The modules in
+//! here can be used with `#[serde(with = "...")]` to serialize other
+//! representations of time as RFC3339 strings, without any allocation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "rfc3339::serde::unix_seconds")]
+//!     time: u64,
+//!     value: f32,
+//! }
+//!
+//! let json = serde_json::to_string(&Reading { time: 1445470140, value: 1.5 }).unwrap();
+//! assert_eq!(json, r#"{"time":"2015-10-21T23:29:00Z","value":1.5}"#);
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use crate::parse::parse_datetime;
+use crate::{format_unix_nanos_with, parse, DateTime, FormatOptions, Precision, Timestamp};
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor(|s: &str| s.parse::<Timestamp>()))
+    }
+}
+
+/// Serializes with as many fractional digits as needed, so no precision is
+/// lost.
+impl Serialize for DateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Timestamp::from_datetime(self, Precision::Auto))
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor(parse_datetime))
+    }
+}
+
+/// A visitor accepting an RFC3339 string, converted with the given parser.
+struct Rfc3339Visitor<F>(F);
+
+impl<T, E, F> Visitor<'_> for Rfc3339Visitor<F>
+where
+    F: FnOnce(&str) -> Result<T, E>,
+    E: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an RFC3339 timestamp")
+    }
+
+    fn visit_str<Er: de::Error>(self, v: &str) -> Result<T, Er> {
+        (self.0)(v).map_err(Er::custom)
+    }
+}
+
+/// Serializes `(seconds, nanos)` since the Unix Epoch with nanosecond
+/// precision as RFC3339 strings in UTC.
+fn serialize_unix<S: Serializer>(
+    seconds: i64,
+    nanos: u32,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let options = FormatOptions {
+        precision: Precision::Auto,
+        ..FormatOptions::default()
+    };
+    let timestamp = format_unix_nanos_with(seconds, nanos, options).map_err(ser::Error::custom)?;
+
+    serializer.serialize_str(&timestamp)
+}
+
+/// Serializes `u64` seconds since the Unix Epoch as an RFC3339 string.
+///
+/// Deserialization rejects times before the Unix Epoch and truncates any
+/// fractional seconds.
+pub mod unix_seconds {
+    use super::*;
+
+    /// Serializes seconds since the Unix Epoch as an RFC3339 string.
+    pub fn serialize<S: Serializer>(seconds: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        let seconds = i64::try_from(*seconds).map_err(ser::Error::custom)?;
+        serialize_unix(seconds, 0, serializer)
+    }
+
+    /// Deserializes an RFC3339 string into seconds since the Unix Epoch.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let (seconds, _) = deserializer.deserialize_str(Rfc3339Visitor(parse))?;
+        u64::try_from(seconds).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes `(i64, u32)` seconds and nanoseconds since the Unix Epoch as an
+/// RFC3339 string.
+pub mod secs_nanos {
+    use super::*;
+
+    /// Serializes seconds and nanoseconds since the Unix Epoch as an RFC3339
+    /// string.
+    pub fn serialize<S: Serializer>(time: &(i64, u32), serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_unix(time.0, time.1, serializer)
+    }
+
+    /// Deserializes an RFC3339 string into seconds and nanoseconds since the
+    /// Unix Epoch.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(i64, u32), D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor(parse))
+    }
+}
+
+/// Serializes [`std::time::SystemTime`] as an RFC3339 string.
+#[cfg(feature = "std")]
+pub mod system_time {
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::parse_system_time;
+
+    /// Serializes a system time as an RFC3339 string.
+    pub fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let datetime = DateTime::try_from(*time).map_err(ser::Error::custom)?;
+        datetime.serialize(serializer)
+    }
+
+    /// Deserializes an RFC3339 string into a system time.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor(parse_system_time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "secs_nanos")]
+        time: (i64, u32),
+        datetime: DateTime,
+        timestamp: Timestamp,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let event = Event {
+            time: (-1, 500_000_000),
+            datetime: "2015-10-21T16:29:00.123-07:00".parse().unwrap(),
+            timestamp: "2015-10-21t23:29:00z".parse().unwrap(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"time":"1969-12-31T23:59:59.5Z","datetime":"2015-10-21T16:29:00.123-07:00","timestamp":"2015-10-21t23:29:00z"}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(serde_json::from_str::<Timestamp>(r#""2015-10-21""#).is_err());
+        assert!(serde_json::from_str::<DateTime>("1445470140").is_err());
+    }
+}
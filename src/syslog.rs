@@ -0,0 +1,317 @@
+//! Syslog timestamps.
+//!
+//! RFC 5424 syslog uses a profile of RFC3339 with at most six fractional
+//! digits, an uppercase `T` and `Z`, and no leap seconds. The older BSD
+//! syslog of RFC 3164 uses local time without a year or offset, such as
+//! `Oct 21 23:29:00`.
+
+use core::fmt;
+
+use crate::buffer::{impl_inline_str, SliceWriter};
+use crate::parse::{check_date, check_time, Cursor, MONTH_NAMES};
+use crate::{DateTime, Error, ParseError, Precision, Timestamp, UtcOffset};
+
+impl DateTime {
+    /// Formats as an RFC 5424 syslog timestamp with the given fractional
+    /// precision of at most six digits.
+    ///
+    /// [`Precision::Auto`] uses up to six digits. Digits beyond microseconds
+    /// are truncated, and a leap second is written as the last microsecond
+    /// of the preceding second.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrecision`] if a fixed precision is not between
+    /// 1 and 6 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{DateTime, Precision};
+    ///
+    /// let datetime = DateTime::from_unix(1445470140, 3_000_500).unwrap();
+    /// let timestamp = datetime.format_rfc5424(Precision::Auto).unwrap();
+    /// assert_eq!(timestamp, "2015-10-21T23:29:00.003Z");
+    /// ```
+    pub fn format_rfc5424(&self, precision: Precision) -> Result<Timestamp, Error> {
+        if let Precision::Digits(7..) = precision {
+            return Err(Error::InvalidPrecision);
+        }
+        precision.validate()?;
+
+        let datetime = match self.second {
+            60 => DateTime {
+                second: 59,
+                nanosecond: 999_999_000,
+                ..*self
+            },
+            _ => DateTime {
+                nanosecond: self.nanosecond - self.nanosecond % 1000,
+                ..*self
+            },
+        };
+        Ok(Timestamp::from_datetime(&datetime, precision))
+    }
+}
+
+/// Formats a Unix timestamp as an RFC 5424 syslog timestamp in UTC with six
+/// fractional digits.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix`] returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_rfc5424;
+///
+/// let timestamp = format_rfc5424(1445470140, 3_000_500).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.003000Z");
+/// ```
+pub fn format_rfc5424(seconds: i64, nanos: u32) -> Result<Timestamp, Error> {
+    DateTime::from_unix(seconds, nanos)?.format_rfc5424(Precision::MICROS)
+}
+
+/// Parses an RFC 5424 syslog timestamp, returning `None` for the NILVALUE
+/// `-` of a message without a timestamp.
+///
+/// # Errors
+///
+/// Returns any error [`parse`](crate::parse) returns, and additionally
+/// [`ParseError::InvalidCharacter`] for a lowercase `t` or `z` or a space as
+/// separator, [`ParseError::InvalidFraction`] for more than six fractional
+/// digits and [`ParseError::LeapSecond`] for a leap second.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{parse_rfc5424, ParseError};
+///
+/// let datetime = parse_rfc5424("2003-10-11T22:14:15.003Z").unwrap().unwrap();
+/// assert_eq!(datetime.to_unix(), (1065910455, 3_000_000));
+///
+/// assert_eq!(parse_rfc5424("-"), Ok(None));
+/// assert_eq!(
+///     parse_rfc5424("2003-10-11T22:14:15.000000003Z"),
+///     Err(ParseError::InvalidFraction)
+/// );
+/// ```
+pub fn parse_rfc5424(input: &str) -> Result<Option<DateTime>, ParseError> {
+    if input == "-" {
+        return Ok(None);
+    }
+
+    let datetime: DateTime = input.parse()?;
+
+    // A successful parse guarantees the fixed layout up to the seconds.
+    let bytes = input.as_bytes();
+    if bytes[10] != b'T' {
+        return Err(ParseError::InvalidCharacter(10));
+    }
+    if bytes[bytes.len() - 1] == b'z' {
+        return Err(ParseError::InvalidCharacter(bytes.len() - 1));
+    }
+    let digits = bytes[20..].iter().take_while(|b| b.is_ascii_digit());
+    if bytes[19] == b'.' && digits.count() > 6 {
+        return Err(ParseError::InvalidFraction);
+    }
+    if datetime.second == 60 {
+        return Err(ParseError::LeapSecond);
+    }
+
+    Ok(Some(datetime))
+}
+
+/// A BSD syslog timestamp of RFC 3164, e.g. `Oct 21 23:29:00`, with the day
+/// of the month padded with a space.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_rfc3164, UtcOffset};
+///
+/// let timestamp = format_rfc3164(1444001340, UtcOffset::UTC).unwrap();
+/// assert_eq!(timestamp, "Oct  4 23:29:00");
+/// assert_eq!(timestamp.len(), 15);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BsdTimestamp {
+    buf: [u8; BsdTimestamp::LEN],
+}
+
+impl BsdTimestamp {
+    /// The length of a BSD syslog timestamp.
+    pub const LEN: usize = 15;
+
+    /// Returns the timestamp as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf).expect("BSD timestamps are ASCII")
+    }
+}
+
+impl_inline_str!(BsdTimestamp);
+
+/// Formats Unix seconds as a BSD syslog timestamp in local time at the given
+/// offset.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix_offset`] returns.
+pub fn format_rfc3164(seconds: i64, offset: UtcOffset) -> Result<BsdTimestamp, Error> {
+    let datetime = DateTime::from_unix_offset(seconds, 0, offset)?;
+
+    let mut buf = [0; BsdTimestamp::LEN];
+    let mut writer = SliceWriter::new(&mut buf);
+    fmt::write(
+        &mut writer,
+        format_args!(
+            "{} {:2} {:02}:{:02}:{:02}",
+            MONTH_NAMES[datetime.month as usize - 1],
+            datetime.day,
+            datetime.hour,
+            datetime.minute,
+            datetime.second
+        ),
+    )
+    .expect("BSD timestamps have a fixed length");
+
+    Ok(BsdTimestamp { buf })
+}
+
+/// Parses a BSD syslog timestamp in local time at the given offset.
+///
+/// The year is not part of the timestamp, so it is taken from the year before,
+/// of or after the reference time `now`, in Unix seconds, whichever gives the
+/// time closest to it. This handles messages from the end of December read in
+/// January, and clocks slightly ahead around the new year. The day of the
+/// month may be padded with a space or a zero.
+///
+/// In a BSD syslog message the timestamp is the 15 bytes following the
+/// priority, slice them off before parsing.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidCharacter`] or [`ParseError::UnexpectedEnd`]
+/// if the input does not match the format, [`ParseError::InvalidYear`] if
+/// `now` is out of range, and the range errors of [`parse`](crate::parse)
+/// for an invalid date or time, such as February 29 with no leap year near
+/// `now`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{parse_rfc3164, UtcOffset};
+///
+/// // Read on 2016-01-01T00:00:10Z, a message from the previous year.
+/// let now = 1451606410;
+/// let datetime = parse_rfc3164("Dec 31 23:59:50", now, UtcOffset::UTC).unwrap();
+/// assert_eq!(datetime.to_string(), "2015-12-31T23:59:50.000000Z");
+/// ```
+pub fn parse_rfc3164(input: &str, now: i64, offset: UtcOffset) -> Result<DateTime, ParseError> {
+    let mut cursor = Cursor::new(input);
+
+    let month = cursor.name(&MONTH_NAMES)? as u32 + 1;
+    cursor.expect(b" ")?;
+    let day = match cursor.peek() {
+        Some(b' ') => {
+            cursor.pos += 1;
+            cursor.digits(1)?
+        }
+        _ => cursor.digits(2)?,
+    };
+    cursor.expect(b" ")?;
+    let hour = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let minute = cursor.digits(2)?;
+    cursor.expect(b":")?;
+    let second = cursor.digits(2)?;
+    if cursor.peek().is_some() {
+        return Err(ParseError::TrailingCharacters);
+    }
+    check_time(hour, minute, second)?;
+
+    let current = DateTime::from_unix_offset(now, 0, offset)
+        .map_err(|_| ParseError::InvalidYear)?
+        .year as u32;
+
+    let mut closest: Option<(u64, DateTime)> = None;
+    let mut error = ParseError::InvalidDay;
+    for year in current - 1..=(current + 1).min(9999) {
+        if let Err(err) = check_date(year, month, day) {
+            error = err;
+            continue;
+        }
+
+        let datetime = DateTime {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond: 0,
+            offset,
+        };
+        let distance = datetime.to_unix().0.abs_diff(now);
+        if closest.is_none_or(|(closest, _)| distance < closest) {
+            closest = Some((distance, datetime));
+        }
+    }
+
+    closest.map(|(_, datetime)| datetime).ok_or(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc5424() {
+        let leap: DateTime = "2016-12-31T23:59:60.5Z".parse().unwrap();
+        assert_eq!(
+            leap.format_rfc5424(Precision::MILLIS).unwrap(),
+            "2016-12-31T23:59:59.999Z"
+        );
+        assert_eq!(
+            leap.format_rfc5424(Precision::NANOS),
+            Err(Error::InvalidPrecision)
+        );
+
+        for (input, err) in [
+            ("2016-12-31t23:59:59Z", ParseError::InvalidCharacter(10)),
+            ("2016-12-31 23:59:59Z", ParseError::InvalidCharacter(10)),
+            ("2016-12-31T23:59:59z", ParseError::InvalidCharacter(19)),
+            ("2016-12-31T23:59:60Z", ParseError::LeapSecond),
+            ("", ParseError::UnexpectedEnd),
+        ] {
+            assert_eq!(parse_rfc5424(input), Err(err), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rfc3164() {
+        let cet = UtcOffset::from_minutes(60).unwrap();
+        assert_eq!(format_rfc3164(1445470140, cet).unwrap(), "Oct 22 00:29:00");
+
+        // 2016-01-01T00:00:00Z
+        let now = 1451606400;
+        let parse = |input| parse_rfc3164(input, now, UtcOffset::UTC).map(|dt| dt.to_unix().0);
+        assert_eq!(parse("Jan  1 00:00:05"), Ok(now + 5));
+        assert_eq!(parse("Jan 01 00:00:05"), Ok(now + 5));
+        assert_eq!(parse("Dec 31 23:59:55"), Ok(now - 5));
+        assert_eq!(parse("Jul  1 00:00:00"), Ok(1467331200));
+        assert_eq!(parse("Feb 29 00:00:00"), Ok(1456704000));
+        assert_eq!(
+            parse("Oct 21 23:29:00 host"),
+            Err(ParseError::TrailingCharacters)
+        );
+        assert_eq!(
+            parse("Okt 21 23:29:00"),
+            Err(ParseError::InvalidCharacter(0))
+        );
+
+        let parse = |input| parse_rfc3164(input, 1767225600, UtcOffset::UTC);
+        assert_eq!(parse("Feb 29 00:00:00"), Err(ParseError::InvalidDay));
+    }
+}
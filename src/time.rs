@@ -0,0 +1,173 @@
+//! Conversions from [`core::time::Duration`] and [`std::time::SystemTime`].
+
+use core::time::Duration;
+
+use crate::{format_unix_nanos_with, DateTime, Error, FormatOptions, Timestamp};
+
+#[cfg(feature = "std")]
+use crate::{parse, ParseError};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Converts a duration since the Unix Epoch into an RFC3339 formatted
+/// date-time string in UTC.
+///
+/// # Errors
+///
+/// Returns [`Error::YearOutOfRange`] if the time is after
+/// [`MAX_UNIX_SECONDS`](crate::MAX_UNIX_SECONDS), and [`Error::Overflow`] if
+/// the duration is longer than `i64::MAX` seconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+/// use rfc3339::format_duration;
+///
+/// let timestamp = format_duration(Duration::new(1445470140, 500_000)).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T23:29:00.000500Z");
+/// ```
+pub fn format_duration(since_epoch: Duration) -> Result<Timestamp, Error> {
+    let (seconds, nanos) = unix_from_duration(since_epoch)?;
+    format_unix_nanos_with(seconds, nanos, FormatOptions::default())
+}
+
+/// Converts a system time into an RFC3339 formatted date-time string in UTC,
+/// including times before the Unix Epoch.
+///
+/// # Errors
+///
+/// Returns [`Error::YearOutOfRange`] if the time is outside of the years 0001
+/// to 9999, and [`Error::Overflow`] if it is more than `i64::MAX` seconds
+/// away from the Unix Epoch.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use rfc3339::format_system_time;
+///
+/// let time = UNIX_EPOCH - Duration::from_millis(1);
+/// let timestamp = format_system_time(time).unwrap();
+/// assert_eq!(timestamp, "1969-12-31T23:59:59.999000Z");
+/// ```
+#[cfg(feature = "std")]
+pub fn format_system_time(time: SystemTime) -> Result<Timestamp, Error> {
+    let (seconds, nanos) = unix_from_system_time(time)?;
+    format_unix_nanos_with(seconds, nanos, FormatOptions::default())
+}
+
+/// Parses an RFC3339 `date-time` into a system time.
+///
+/// # Errors
+///
+/// Returns any error [`parse`] returns, and [`ParseError::InvalidYear`] if the
+/// time cannot be represented by [`SystemTime`] on this platform.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use rfc3339::parse_system_time;
+///
+/// let time = parse_system_time("2015-10-21T16:29:00-07:00").unwrap();
+/// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(1445470140));
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_system_time(input: &str) -> Result<SystemTime, ParseError> {
+    let (seconds, nanos) = parse(input)?;
+    system_time_from_unix(seconds, nanos).ok_or(ParseError::InvalidYear)
+}
+
+fn unix_from_duration(since_epoch: Duration) -> Result<(i64, u32), Error> {
+    let seconds = i64::try_from(since_epoch.as_secs()).map_err(|_| Error::Overflow)?;
+    Ok((seconds, since_epoch.subsec_nanos()))
+}
+
+#[cfg(feature = "std")]
+fn unix_from_system_time(time: SystemTime) -> Result<(i64, u32), Error> {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since_epoch) => unix_from_duration(since_epoch),
+        Err(err) => {
+            let (seconds, nanos) = unix_from_duration(err.duration())?;
+            if nanos == 0 {
+                Ok((-seconds, 0))
+            } else {
+                Ok((-seconds - 1, 1_000_000_000 - nanos))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn system_time_from_unix(seconds: i64, nanos: u32) -> Option<SystemTime> {
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(seconds as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(seconds.unsigned_abs()))?
+            .checked_add(Duration::from_nanos(nanos as u64))
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from a duration since the Unix Epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::YearOutOfRange`] if the time is after
+    /// [`MAX_UNIX_SECONDS`](crate::MAX_UNIX_SECONDS), and [`Error::Overflow`]
+    /// if the duration is longer than `i64::MAX` seconds.
+    pub fn from_unix_duration(since_epoch: Duration) -> Result<DateTime, Error> {
+        let (seconds, nanos) = unix_from_duration(since_epoch)?;
+        DateTime::from_unix(seconds, nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for DateTime {
+    type Error = Error;
+
+    /// Fails with [`Error::YearOutOfRange`] if the time is outside of the
+    /// years 0001 to 9999, and [`Error::Overflow`] if it is more than
+    /// `i64::MAX` seconds away from the Unix Epoch.
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let (seconds, nanos) = unix_from_system_time(time)?;
+        DateTime::from_unix(seconds, nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<DateTime> for SystemTime {
+    type Error = Error;
+
+    /// Fails with [`Error::YearOutOfRange`] if the time cannot be represented
+    /// by [`SystemTime`] on this platform.
+    fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+        let (seconds, nanos) = datetime.to_unix();
+        system_time_from_unix(seconds, nanos).ok_or(Error::YearOutOfRange)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_time_round_trip() {
+        for (seconds, nanos) in [(0, 0), (-1, 0), (-2, 999_999_999), (1445470140, 1)] {
+            let time = system_time_from_unix(seconds, nanos).unwrap();
+            assert_eq!(unix_from_system_time(time), Ok((seconds, nanos)));
+
+            let datetime = DateTime::try_from(time).unwrap();
+            assert_eq!(SystemTime::try_from(datetime), Ok(time));
+        }
+    }
+
+    #[test]
+    fn test_duration_overflow() {
+        let duration = Duration::from_secs(u64::MAX);
+        assert_eq!(format_duration(duration), Err(Error::Overflow));
+        assert_eq!(DateTime::from_unix_duration(duration), Err(Error::Overflow));
+    }
+}
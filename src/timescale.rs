@@ -0,0 +1,284 @@
+//! Conversions between the UTC (Unix), TAI and GPS time scales.
+//!
+//! TAI and GPS time count every SI second, including leap seconds, while Unix
+//! time skips them. Converting between them needs a [`LeapSeconds`] table.
+//!
+//! TAI seconds are counted from 1970-01-01T00:00:00 TAI, as in PTP, and GPS
+//! seconds from the GPS epoch 1980-01-06T00:00:00 UTC. GPS time is always 19
+//! seconds behind TAI.
+
+use crate::{DateTime, Error, LeapSeconds, Precision, Timestamp};
+
+/// The GPS epoch, 1980-01-06T00:00:00Z, in Unix time.
+pub const GPS_EPOCH_UNIX: i64 = 315964800;
+
+/// TAI - GPS in seconds.
+pub const TAI_GPS_OFFSET: i64 = 19;
+
+/// The GPS epoch in TAI seconds, TAI - UTC was 19 seconds at that time.
+const GPS_EPOCH_TAI: i64 = GPS_EPOCH_UNIX + TAI_GPS_OFFSET;
+
+/// A time scale that seconds can be counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// UTC as Unix time, seconds since 1970-01-01T00:00:00Z without leap
+    /// seconds.
+    Unix,
+    /// International Atomic Time, seconds since 1970-01-01T00:00:00 TAI.
+    Tai,
+    /// GPS time, seconds since 1980-01-06T00:00:00Z.
+    Gps,
+}
+
+impl TimeScale {
+    /// Converts seconds in this time scale into another.
+    ///
+    /// Converting a leap second into Unix time gives the Unix time of the
+    /// preceding second, as POSIX clocks do.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the result does not fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::{LeapSeconds, TimeScale};
+    ///
+    /// let table = LeapSeconds::BUILTIN;
+    /// let gps = TimeScale::Unix.convert(1445470140, TimeScale::Gps, &table).unwrap();
+    /// assert_eq!(gps, 1129505357);
+    /// assert_eq!(TimeScale::Gps.convert(gps, TimeScale::Unix, &table), Ok(1445470140));
+    /// ```
+    pub fn convert(
+        self,
+        seconds: i64,
+        to: TimeScale,
+        table: &LeapSeconds<'_>,
+    ) -> Result<i64, Error> {
+        let tai = match self {
+            TimeScale::Unix => table.tai_from_unix(seconds)?,
+            TimeScale::Tai => seconds,
+            TimeScale::Gps => seconds.checked_add(GPS_EPOCH_TAI).ok_or(Error::Overflow)?,
+        };
+
+        match to {
+            TimeScale::Unix => table.unix_from_tai(tai).map(|(unix, _)| unix),
+            TimeScale::Tai => Ok(tai),
+            TimeScale::Gps => tai.checked_sub(GPS_EPOCH_TAI).ok_or(Error::Overflow),
+        }
+    }
+}
+
+impl LeapSeconds<'_> {
+    /// Converts Unix time into TAI seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the result does not fit into an `i64`.
+    pub fn tai_from_unix(&self, unix: i64) -> Result<i64, Error> {
+        unix.checked_add(self.tai_offset(unix) as i64)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Converts TAI seconds into Unix time, and whether the TAI second is a
+    /// leap second, in which case the Unix time of the preceding second is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the result does not fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rfc3339::LeapSeconds;
+    ///
+    /// let table = LeapSeconds::BUILTIN;
+    /// assert_eq!(table.unix_from_tai(1483228835), Ok((1483228799, false)));
+    /// assert_eq!(table.unix_from_tai(1483228836), Ok((1483228799, true)));
+    /// assert_eq!(table.unix_from_tai(1483228837), Ok((1483228800, false)));
+    /// ```
+    pub fn unix_from_tai(&self, tai: i64) -> Result<(i64, bool), Error> {
+        let entries = self.entries();
+        let index = entries
+            .partition_point(|entry| entry.unix as i128 + entry.tai_offset as i128 <= tai as i128);
+        let offset = match index {
+            0 => entries.first().map_or(0, |entry| entry.tai_offset),
+            _ => entries[index - 1].tai_offset,
+        };
+        let unix = tai.checked_sub(offset as i64).ok_or(Error::Overflow)?;
+
+        // Still at the old offset, but past the start of the next entry.
+        match entries.get(index) {
+            Some(next) if unix >= next.unix => Ok((next.unix - 1, true)),
+            _ => Ok((unix, false)),
+        }
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in UTC from TAI seconds, a leap second is
+    /// represented as `23:59:60`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the resulting Unix time does not fit
+    /// into an `i64`, and any error [`DateTime::from_unix`] returns for it.
+    pub fn from_tai(seconds: i64, nanos: u32, table: &LeapSeconds<'_>) -> Result<DateTime, Error> {
+        let (unix, leap) = table.unix_from_tai(seconds)?;
+        let datetime = DateTime::from_unix(unix, nanos)?;
+
+        if leap {
+            Ok(DateTime {
+                second: 60,
+                ..datetime
+            })
+        } else {
+            Ok(datetime)
+        }
+    }
+
+    /// Creates a date and time in UTC from GPS seconds, a leap second is
+    /// represented as `23:59:60`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the resulting Unix time does not fit
+    /// into an `i64`, and any error [`DateTime::from_unix`] returns for it.
+    pub fn from_gps(seconds: i64, nanos: u32, table: &LeapSeconds<'_>) -> Result<DateTime, Error> {
+        let tai = seconds.checked_add(GPS_EPOCH_TAI).ok_or(Error::Overflow)?;
+        DateTime::from_tai(tai, nanos, table)
+    }
+
+    /// Converts into TAI seconds and nanoseconds.
+    pub fn to_tai(&self, table: &LeapSeconds<'_>) -> (i64, u32) {
+        let (unix, nanos) = self.to_unix();
+        let tai = table.tai_from_unix(unix).expect("date times are in range");
+
+        // A leap second was folded into the following second by `to_unix`.
+        if self.is_leap_second() {
+            (tai - 1, nanos)
+        } else {
+            (tai, nanos)
+        }
+    }
+
+    /// Converts into GPS seconds and nanoseconds.
+    pub fn to_gps(&self, table: &LeapSeconds<'_>) -> (i64, u32) {
+        let (tai, nanos) = self.to_tai(table);
+        (tai - GPS_EPOCH_TAI, nanos)
+    }
+}
+
+/// Converts TAI seconds with nanosecond resolution into an RFC3339 formatted
+/// date-time string in UTC with nine fractional digits.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix`] returns for the resulting Unix
+/// time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_tai, LeapSeconds};
+///
+/// let timestamp = format_tai(1483228836, 0, &LeapSeconds::BUILTIN).unwrap();
+/// assert_eq!(timestamp, "2016-12-31T23:59:60.000000000Z");
+/// ```
+pub fn format_tai(seconds: i64, nanos: u32, table: &LeapSeconds<'_>) -> Result<Timestamp, Error> {
+    DateTime::from_tai(seconds, nanos, table)?.format(Precision::NANOS)
+}
+
+/// Converts GPS seconds with nanosecond resolution into an RFC3339 formatted
+/// date-time string in UTC with nine fractional digits.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix`] returns for the resulting Unix
+/// time.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_gps, LeapSeconds};
+///
+/// let timestamp = format_gps(0, 0, &LeapSeconds::BUILTIN).unwrap();
+/// assert_eq!(timestamp, "1980-01-06T00:00:00.000000000Z");
+/// ```
+pub fn format_gps(seconds: i64, nanos: u32, table: &LeapSeconds<'_>) -> Result<Timestamp, Error> {
+    DateTime::from_gps(seconds, nanos, table)?.format(Precision::NANOS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LeapSecond;
+
+    #[test]
+    fn test_convert() {
+        let table = LeapSeconds::BUILTIN;
+
+        assert_eq!(
+            TimeScale::Unix.convert(GPS_EPOCH_UNIX, TimeScale::Gps, &table),
+            Ok(0)
+        );
+        assert_eq!(
+            TimeScale::Gps.convert(0, TimeScale::Tai, &table),
+            Ok(GPS_EPOCH_TAI)
+        );
+        assert_eq!(
+            TimeScale::Unix.convert(1483228800, TimeScale::Gps, &table),
+            Ok(1167264018)
+        );
+        assert_eq!(TimeScale::Tai.convert(0, TimeScale::Unix, &table), Ok(-10));
+    }
+
+    #[test]
+    fn test_convert_overflow() {
+        let table = LeapSeconds::BUILTIN;
+
+        for (from, seconds, to) in [
+            (TimeScale::Unix, i64::MAX, TimeScale::Tai),
+            (TimeScale::Gps, i64::MAX, TimeScale::Tai),
+            (TimeScale::Tai, i64::MIN, TimeScale::Unix),
+            (TimeScale::Tai, i64::MIN, TimeScale::Gps),
+        ] {
+            assert_eq!(from.convert(seconds, to, &table), Err(Error::Overflow));
+        }
+        assert_eq!(
+            TimeScale::Gps.convert(i64::MIN, TimeScale::Unix, &table),
+            Ok(i64::MIN + GPS_EPOCH_TAI - 10)
+        );
+        assert_eq!(
+            DateTime::from_tai(i64::MIN, 0, &table),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            DateTime::from_gps(i64::MAX, 0, &table),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_custom_table_limits() {
+        let entries = [LeapSecond::new(0, 10), LeapSecond::new(i64::MAX, 11)];
+        let table = LeapSeconds::new(&entries, None);
+
+        assert_eq!(table.unix_from_tai(100), Ok((90, false)));
+        assert_eq!(table.unix_from_tai(i64::MAX), Ok((i64::MAX - 10, false)));
+        assert_eq!(table.tai_from_unix(i64::MAX), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_leap_second_round_trip() {
+        let table = LeapSeconds::BUILTIN;
+
+        for tai in 1483228834..1483228839 {
+            let datetime = DateTime::from_tai(tai, 0, &table).unwrap();
+            assert_eq!(datetime.to_tai(&table), (tai, 0));
+            assert_eq!(datetime.is_leap_second(), tai == 1483228836);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Stack allocated RFC3339 timestamp strings.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::buffer::impl_inline_str;
+use crate::parse::parse_datetime;
+use crate::{DateTime, ParseError, Precision};
+
+/// A timestamp in RFC3339 format.
+///
+/// The string is stored inline so it is `Copy` and behaves the same with and
+/// without the `std` feature. It dereferences to a `str` for everything else.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::format_unix;
+///
+/// let timestamp = format_unix(1609459200, 0);
+/// assert_eq!(timestamp, "2021-01-01T00:00:00.000000Z");
+/// assert!(timestamp.starts_with("2021-01-01"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Timestamp {
+    buf: [u8; Timestamp::MAX_LEN],
+    len: u8,
+}
+
+impl Timestamp {
+    /// The length of the longest possible timestamp, e.g.
+    /// `2015-10-21T23:29:00.123456789+05:30`.
+    pub const MAX_LEN: usize = 35;
+
+    /// Formats a date and time, the precision must be valid.
+    pub(crate) fn from_datetime(datetime: &DateTime, precision: Precision) -> Self {
+        let mut buf = [0; Timestamp::MAX_LEN];
+        let len = match datetime.format_into(precision, &mut buf) {
+            Ok(output) => output.len(),
+            Err(_) => 0,
+        };
+
+        Timestamp {
+            buf,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the timestamp as a string slice.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len as usize]).expect("timestamps are ASCII")
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseError;
+
+    /// Validates an RFC3339 timestamp and copies it as is.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_datetime(s)?;
+
+        // Anything accepted by the parser fits.
+        let mut buf = [0; Timestamp::MAX_LEN];
+        buf[..s.len()].copy_from_slice(s.as_bytes());
+        Ok(Timestamp {
+            buf,
+            len: s.len() as u8,
+        })
+    }
+}
+
+impl_inline_str!(Timestamp);
+
+impl AsRef<[u8]> for Timestamp {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for Timestamp {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialEq<Timestamp> for str {
+    fn eq(&self, other: &Timestamp) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Timestamp> for &str {
+    fn eq(&self, other: &Timestamp) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        let datetime = DateTime::from_unix(1445470140, 0).unwrap();
+        let a = Timestamp::from_datetime(&datetime, Precision::Seconds);
+        let b = a;
+
+        assert_eq!(a, b);
+        assert_eq!(a, "2015-10-21T23:29:00Z");
+        assert_eq!("2015-10-21T23:29:00Z", a);
+        assert_eq!(a.len(), 20);
+
+        let datetime = DateTime::from_unix(1445470141, 0).unwrap();
+        let c = Timestamp::from_datetime(&datetime, Precision::Seconds);
+        assert!(a < c);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let longest = "2015-10-21t23:29:00.123456789+05:30";
+        assert_eq!(longest.parse::<Timestamp>().unwrap(), longest);
+        assert_eq!(
+            "2015-10-21".parse::<Timestamp>(),
+            Err(ParseError::UnexpectedEnd)
+        );
+    }
+}
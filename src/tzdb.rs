@@ -0,0 +1,180 @@
+//! An embedded subset of the IANA time zone database.
+//!
+//! Each zone is stored as the POSIX TZ string it currently follows, taken from
+//! the footer of its TZif file, rather than its full history of transitions,
+//! along with the time from which the rule matches that history. Times before
+//! the last change to a zone's rules have no known offset and are rejected
+//! with [`Error::UnknownOffset`], load a [`Tzif`](crate::Tzif) file where
+//! historic offsets matter.
+//!
+//! The `tzdb` feature includes `UTC` and the `Etc/*` zones. The zones of each
+//! region are included with a feature per region, `tzdb-africa`,
+//! `tzdb-america`, `tzdb-antarctica`, `tzdb-arctic`, `tzdb-asia`,
+//! `tzdb-atlantic`, `tzdb-australia`, `tzdb-europe`, `tzdb-indian` and
+//! `tzdb-pacific`, or all of them with `tzdb-all`.
+//!
+//! Zones whose future transitions are not described by a rule, currently
+//! `Africa/Casablanca`, `Africa/El_Aaiun`, `Asia/Gaza` and `Asia/Hebron`, are
+//! left out rather than given wrong offsets, load their TZif files instead.
+//!
+//! Zones can only be selected by region through features. For a smaller
+//! table, regenerate the data with the zones or regions to keep as arguments,
+//! e.g. `cargo run --release --example gen_tzdb -- Europe America/New_York`,
+//! which
+//! always keeps `UTC` and `Etc/*`.
+//!
+//! The data is generated with `cargo run --release --example gen_tzdb`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "tzdb-europe")] {
+//! use rfc3339::{format_unix_zone, tzdb};
+//!
+//! let berlin = tzdb::get("Europe/Berlin").unwrap();
+//! let timestamp = format_unix_zone(1445470140, 0, &berlin).unwrap();
+//! assert_eq!(timestamp, "2015-10-22T01:29:00.000000+02:00");
+//!
+//! // Before the current EU rules of 1996.
+//! assert!(format_unix_zone(794880000, 0, &berlin).is_err());
+//! # }
+//! ```
+
+use crate::{Error, LocalTimeType, PosixTz, TimeZone, UtcOffset};
+
+mod data;
+
+/// The version of the tz database the zones were generated from, such as
+/// `2025b`.
+pub const VERSION: &str = data::VERSION;
+
+/// The included zones by region, each sorted by name.
+const REGIONS: &[&[(&str, i64, &str)]] = &[
+    data::ETC,
+    #[cfg(feature = "tzdb-africa")]
+    data::AFRICA,
+    #[cfg(feature = "tzdb-america")]
+    data::AMERICA,
+    #[cfg(feature = "tzdb-antarctica")]
+    data::ANTARCTICA,
+    #[cfg(feature = "tzdb-arctic")]
+    data::ARCTIC,
+    #[cfg(feature = "tzdb-asia")]
+    data::ASIA,
+    #[cfg(feature = "tzdb-atlantic")]
+    data::ATLANTIC,
+    #[cfg(feature = "tzdb-australia")]
+    data::AUSTRALIA,
+    #[cfg(feature = "tzdb-europe")]
+    data::EUROPE,
+    #[cfg(feature = "tzdb-indian")]
+    data::INDIAN,
+    #[cfg(feature = "tzdb-pacific")]
+    data::PACIFIC,
+];
+
+/// An included zone, the rule it currently follows and the time from which
+/// the rule applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zone {
+    rule: PosixTz<'static>,
+    since: i64,
+}
+
+impl Zone {
+    /// Returns the rule the zone currently follows.
+    pub fn rule(&self) -> PosixTz<'static> {
+        self.rule
+    }
+
+    /// Returns the Unix time from which the rule matches the history of the
+    /// zone, `i64::MIN` if it always did.
+    pub fn since(&self) -> i64 {
+        self.since
+    }
+
+    /// Returns the local time type in effect at the given Unix time, or
+    /// `None` before [`Zone::since`].
+    pub fn local_time_type(&self, seconds: i64) -> Option<LocalTimeType<'static>> {
+        (seconds >= self.since).then(|| self.rule.local_time_type(seconds))
+    }
+}
+
+impl TimeZone for Zone {
+    /// Returns the offset of the local time type in effect, truncated to whole
+    /// minutes, or [`Error::UnknownOffset`] before [`Zone::since`].
+    fn utc_offset(&self, seconds: i64) -> Result<UtcOffset, Error> {
+        let local_time_type = self.local_time_type(seconds).ok_or(Error::UnknownOffset)?;
+        UtcOffset::from_seconds(local_time_type.offset_seconds())
+    }
+}
+
+/// Looks up an included zone by its name, such as `Europe/Berlin`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::tzdb;
+///
+/// let utc = tzdb::get("UTC").unwrap();
+/// assert_eq!(utc.local_time_type(0).unwrap().abbreviation(), "UTC");
+/// assert!(tzdb::get("Mars/Olympus_Mons").is_none());
+/// ```
+pub fn get(name: &str) -> Option<Zone> {
+    let (since, rule) = REGIONS.iter().find_map(|zones| {
+        let index = zones
+            .binary_search_by_key(&name, |&(name, _, _)| name)
+            .ok()?;
+        Some((zones[index].1, zones[index].2))
+    })?;
+
+    Some(Zone {
+        rule: PosixTz::parse(rule).ok()?,
+        since,
+    })
+}
+
+/// Returns the names of all included zones.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    REGIONS
+        .iter()
+        .flat_map(|zones| zones.iter().map(|&(name, _, _)| name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zones() {
+        for zones in REGIONS {
+            assert!(zones.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        for name in names() {
+            assert!(get(name).is_some(), "{}", name);
+        }
+
+        let utc = get("Etc/UTC").unwrap();
+        assert_eq!(utc.since(), i64::MIN);
+        assert_eq!(utc.local_time_type(i64::MIN).unwrap().offset_seconds(), 0);
+
+        // Its rule misses the yearly change to +00 during Ramadan.
+        assert!(get("Africa/Casablanca").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "tzdb-america")]
+    fn test_history() {
+        // Daylight saving time until February 2019, not in today's rule.
+        let sao_paulo = get("America/Sao_Paulo").unwrap();
+        assert_eq!(sao_paulo.local_time_type(1514764800), None);
+        assert_eq!(
+            crate::format_unix_zone(1514764800, 0, &sao_paulo),
+            Err(Error::UnknownOffset)
+        );
+        assert_eq!(
+            crate::format_unix_zone(1577836800, 0, &sao_paulo).unwrap(),
+            "2019-12-31T21:00:00.000000-03:00"
+        );
+    }
+}
@@ -0,0 +1,756 @@
+//! Generated by `cargo run --release --example gen_tzdb`, do not edit.
+
+/// The version of the tz database the zones were generated from.
+pub(super) const VERSION: &str = "2025b";
+
+// Left out, as their rules do not describe their future transitions:
+// Africa/Casablanca
+// Africa/El_Aaiun
+// Asia/Gaza
+// Asia/Hebron
+
+#[cfg(feature = "tzdb-africa")]
+pub(super) const AFRICA: &[(&str, i64, &str)] = &[
+    ("Africa/Abidjan", -1830383032, "GMT0"),
+    ("Africa/Accra", -441844200, "GMT0"),
+    ("Africa/Addis_Ababa", -1062210920, "EAT-3"),
+    ("Africa/Algiers", 357523200, "CET-1"),
+    ("Africa/Asmara", -1062210920, "EAT-3"),
+    ("Africa/Asmera", -865305900, "EAT-3"),
+    ("Africa/Bamako", -300841200, "GMT0"),
+    ("Africa/Bangui", -1830388460, "WAT-1"),
+    ("Africa/Banjul", -880930800, "GMT0"),
+    ("Africa/Bissau", 157770000, "GMT0"),
+    ("Africa/Blantyre", -1404440460, "CAT-2"),
+    ("Africa/Brazzaville", -1830387668, "WAT-1"),
+    ("Africa/Bujumbura", -2524528648, "CAT-2"),
+    ("Africa/Cairo", 1666904400, "EET-2EEST,M4.5.5/0,M10.5.4/24"),
+    ("Africa/Ceuta", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Africa/Conakry", -315615600, "GMT0"),
+    ("Africa/Dakar", -902098800, "GMT0"),
+    ("Africa/Dar_es_Salaam", -284006700, "EAT-3"),
+    ("Africa/Djibouti", -1846291956, "EAT-3"),
+    ("Africa/Douala", -1830386328, "WAT-1"),
+    ("Africa/Freetown", -885769200, "GMT0"),
+    ("Africa/Gaborone", -813805200, "CAT-2"),
+    ("Africa/Harare", -2109290652, "CAT-2"),
+    ("Africa/Johannesburg", -813805200, "SAST-2"),
+    ("Africa/Juba", 1612126800, "CAT-2"),
+    ("Africa/Kampala", -410237100, "EAT-3"),
+    ("Africa/Khartoum", 1509483600, "CAT-2"),
+    ("Africa/Kigali", -1091498416, "CAT-2"),
+    ("Africa/Kinshasa", -2276643672, "WAT-1"),
+    ("Africa/Lagos", -1588465800, "WAT-1"),
+    ("Africa/Libreville", -1830386268, "WAT-1"),
+    ("Africa/Lome", -2429827492, "GMT0"),
+    ("Africa/Luanda", -1830387600, "WAT-1"),
+    ("Africa/Lubumbashi", -1567990800, "CAT-2"),
+    ("Africa/Lusaka", -2109289988, "CAT-2"),
+    ("Africa/Malabo", -190857600, "WAT-1"),
+    ("Africa/Maputo", -1924999818, "CAT-2"),
+    ("Africa/Maseru", -813805200, "SAST-2"),
+    ("Africa/Mbabane", -2109290664, "SAST-2"),
+    ("Africa/Mogadishu", -410236200, "EAT-3"),
+    ("Africa/Monrovia", 63593070, "GMT0"),
+    ("Africa/Nairobi", -865305900, "EAT-3"),
+    ("Africa/Ndjamena", 321314400, "WAT-1"),
+    ("Africa/Niamey", -315619200, "WAT-1"),
+    ("Africa/Nouakchott", -286930800, "GMT0"),
+    ("Africa/Ouagadougou", -1830383636, "GMT0"),
+    ("Africa/Porto-Novo", -1131235200, "WAT-1"),
+    ("Africa/Sao_Tome", 1546304400, "GMT0"),
+    ("Africa/Timbuktu", -1830383032, "GMT0"),
+    ("Africa/Tripoli", 1382659200, "EET-2"),
+    ("Africa/Tunis", 1224982800, "CET-1"),
+    ("Africa/Windhoek", 1504400400, "CAT-2"),
+];
+
+#[cfg(feature = "tzdb-america")]
+pub(super) const AMERICA: &[(&str, i64, &str)] = &[
+    ("America/Adak", 1162724400, "HST10HDT,M3.2.0,M11.1.0"),
+    ("America/Anchorage", 1162720800, "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Anguilla", -1825098464, "AST4"),
+    ("America/Antigua", -599598000, "AST4"),
+    ("America/Araguaina", 1361066400, "<-03>3"),
+    ("America/Argentina/Buenos_Aires", 1237082400, "<-03>3"),
+    ("America/Argentina/Catamarca", 1205632800, "<-03>3"),
+    ("America/Argentina/ComodRivadavia", 1205632800, "<-03>3"),
+    ("America/Argentina/Cordoba", 1237082400, "<-03>3"),
+    ("America/Argentina/Jujuy", 1205632800, "<-03>3"),
+    ("America/Argentina/La_Rioja", 1205632800, "<-03>3"),
+    ("America/Argentina/Mendoza", 1205632800, "<-03>3"),
+    ("America/Argentina/Rio_Gallegos", 1205632800, "<-03>3"),
+    ("America/Argentina/Salta", 1205632800, "<-03>3"),
+    ("America/Argentina/San_Juan", 1205632800, "<-03>3"),
+    ("America/Argentina/San_Luis", 1255233600, "<-03>3"),
+    ("America/Argentina/Tucuman", 1237082400, "<-03>3"),
+    ("America/Argentina/Ushuaia", 1205632800, "<-03>3"),
+    ("America/Aruba", -157750200, "AST4"),
+    ("America/Asuncion", 1728187200, "<-03>3"),
+    ("America/Atikokan", -765392400, "EST5"),
+    ("America/Atka", 1162724400, "HST10HDT,M3.2.0,M11.1.0"),
+    ("America/Bahia", 1330221600, "<-03>3"),
+    ("America/Bahia_Banderas", 1667113200, "CST6"),
+    ("America/Barbados", 338706000, "AST4"),
+    ("America/Belem", 571197600, "<-03>3"),
+    ("America/Belize", 413874000, "CST6"),
+    ("America/Blanc-Sablon", -765399600, "AST4"),
+    ("America/Boa_Vista", 971578800, "<-04>4"),
+    ("America/Bogota", 729057600, "<-05>5"),
+    ("America/Boise", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Buenos_Aires", 1237082400, "<-03>3"),
+    (
+        "America/Cambridge_Bay",
+        1162713600,
+        "MST7MDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Campo_Grande", 1550372400, "<-04>4"),
+    ("America/Cancun", 1422777600, "EST5"),
+    ("America/Caracas", 1462086000, "<-04>4"),
+    ("America/Catamarca", 1205632800, "<-03>3"),
+    ("America/Cayenne", -71092800, "<-03>3"),
+    ("America/Cayman", -1827687170, "EST5"),
+    ("America/Chicago", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Chihuahua", 1667116800, "CST6"),
+    (
+        "America/Ciudad_Juarez",
+        1669788000,
+        "MST7MDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Coral_Harbour", -1946918424, "EST5"),
+    ("America/Cordoba", 1237082400, "<-03>3"),
+    ("America/Costa_Rica", 700635600, "CST6"),
+    ("America/Coyhaique", 1725768000, "<-03>3"),
+    ("America/Creston", -1627833600, "MST7"),
+    ("America/Cuiaba", 1550372400, "<-04>4"),
+    ("America/Curacao", -157750200, "AST4"),
+    ("America/Danmarkshavn", 820465200, "GMT0"),
+    ("America/Dawson", 1604214000, "MST7"),
+    ("America/Dawson_Creek", 84013200, "MST7"),
+    ("America/Denver", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Detroit", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Dominica", -1846266804, "AST4"),
+    ("America/Edmonton", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Eirunepe", 1384056000, "<-05>5"),
+    ("America/El_Salvador", 591166800, "CST6"),
+    ("America/Ensenada", 1257066000, "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Fort_Nelson", 1425808800, "MST7"),
+    ("America/Fort_Wayne", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Fortaleza", 1013911200, "<-03>3"),
+    ("America/Glace_Bay", 1162702800, "AST4ADT,M3.2.0,M11.1.0"),
+    (
+        "America/Godthab",
+        1698541200,
+        "<-02>2<-01>,M3.5.0/-1,M10.5.0/0",
+    ),
+    ("America/Goose_Bay", 1299996000, "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Grand_Turk", 1520751600, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Grenada", -1846266780, "AST4"),
+    ("America/Guadeloupe", -1848254032, "AST4"),
+    ("America/Guatemala", 1159678800, "CST6"),
+    ("America/Guayaquil", 728884800, "<-05>5"),
+    ("America/Guyana", 701841600, "<-04>4"),
+    ("America/Halifax", 1162702800, "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Havana", 1333256400, "CST5CDT,M3.2.0/0,M11.1.0/1"),
+    ("America/Hermosillo", 909302400, "MST7"),
+    (
+        "America/Indiana/Indianapolis",
+        1162706400,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Indiana/Knox", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    (
+        "America/Indiana/Marengo",
+        1162706400,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Indiana/Petersburg",
+        1194159600,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Indiana/Tell_City",
+        1162710000,
+        "CST6CDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Indiana/Vevay",
+        1162706400,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Indiana/Vincennes",
+        1194159600,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Indiana/Winamac",
+        1173600000,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Indianapolis", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Inuvik", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Iqaluit", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Jamaica", 436341600, "EST5"),
+    ("America/Jujuy", 1205632800, "<-03>3"),
+    ("America/Juneau", 1162720800, "AKST9AKDT,M3.2.0,M11.1.0"),
+    (
+        "America/Kentucky/Louisville",
+        1162706400,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Kentucky/Monticello",
+        1162706400,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Knox_IN", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Kralendijk", -765399600, "AST4"),
+    ("America/La_Paz", -1192307244, "<-04>4"),
+    ("America/Lima", 765172800, "<-05>5"),
+    ("America/Los_Angeles", 1162717200, "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Louisville", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Lower_Princes", -765399600, "AST4"),
+    ("America/Maceio", 1013911200, "<-03>3"),
+    ("America/Managua", 1159682400, "CST6"),
+    ("America/Manaus", 761713200, "<-04>4"),
+    ("America/Marigot", -765399600, "AST4"),
+    ("America/Martinique", 338958000, "AST4"),
+    ("America/Matamoros", 1257058800, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Mazatlan", 1667116800, "MST7"),
+    ("America/Mendoza", 1205632800, "<-03>3"),
+    ("America/Menominee", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Merida", 1667113200, "CST6"),
+    ("America/Metlakatla", 1547978400, "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Mexico_City", 1667113200, "CST6"),
+    ("America/Miquelon", 1162699200, "<-03>3<-02>,M3.2.0,M11.1.0"),
+    ("America/Moncton", 1162702800, "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Monterrey", 1667113200, "CST6"),
+    ("America/Montevideo", 1425787200, "<-03>3"),
+    ("America/Montreal", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Montserrat", -1846266608, "AST4"),
+    ("America/Nassau", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/New_York", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Nipigon", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Nome", 1162720800, "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Noronha", 1013907600, "<-02>2"),
+    (
+        "America/North_Dakota/Beulah",
+        1289116800,
+        "CST6CDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/North_Dakota/Center",
+        1162710000,
+        "CST6CDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/North_Dakota/New_Salem",
+        1162710000,
+        "CST6CDT,M3.2.0,M11.1.0",
+    ),
+    (
+        "America/Nuuk",
+        1698541200,
+        "<-02>2<-01>,M3.5.0/-1,M10.5.0/0",
+    ),
+    ("America/Ojinaga", 1667718000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Panama", -1946918424, "EST5"),
+    ("America/Pangnirtung", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Paramaribo", 465449400, "<-03>3"),
+    ("America/Phoenix", -68659200, "MST7"),
+    (
+        "America/Port-au-Prince",
+        1478412000,
+        "EST5EDT,M3.2.0,M11.1.0",
+    ),
+    ("America/Port_of_Spain", -1825098836, "AST4"),
+    ("America/Porto_Acre", 1384056000, "<-05>5"),
+    ("America/Porto_Velho", 571201200, "<-04>4"),
+    ("America/Puerto_Rico", -765399600, "AST4"),
+    ("America/Punta_Arenas", 1471147200, "<-03>3"),
+    ("America/Rainy_River", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Rankin_Inlet", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Recife", 1013911200, "<-03>3"),
+    ("America/Regina", -305737200, "CST6"),
+    ("America/Resolute", 1173600000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Rio_Branco", 1384056000, "<-05>5"),
+    ("America/Rosario", 1237082400, "<-03>3"),
+    ("America/Santa_Isabel", 1257066000, "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Santarem", 1214280000, "<-03>3"),
+    (
+        "America/Santiago",
+        1662868800,
+        "<-04>4<-03>,M9.1.6/24,M4.1.6/24",
+    ),
+    ("America/Santo_Domingo", 975823200, "AST4"),
+    ("America/Sao_Paulo", 1550368800, "<-03>3"),
+    (
+        "America/Scoresbysund",
+        1711846800,
+        "<-02>2<-01>,M3.5.0/-1,M10.5.0/0",
+    ),
+    ("America/Shiprock", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Sitka", 1162720800, "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/St_Barthelemy", -765399600, "AST4"),
+    ("America/St_Johns", 1299994200, "NST3:30NDT,M3.2.0,M11.1.0"),
+    ("America/St_Kitts", -1825098548, "AST4"),
+    ("America/St_Lucia", -1830369360, "AST4"),
+    ("America/St_Thomas", -1846266016, "AST4"),
+    ("America/St_Vincent", -1830369304, "AST4"),
+    ("America/Swift_Current", 73472400, "CST6"),
+    ("America/Tegucigalpa", 1154926800, "CST6"),
+    ("America/Thule", 1162702800, "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Thunder_Bay", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Tijuana", 1257066000, "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Toronto", 1162706400, "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Tortola", -1846266092, "AST4"),
+    ("America/Vancouver", 1162717200, "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Virgin", -765399600, "AST4"),
+    ("America/Whitehorse", 1604214000, "MST7"),
+    ("America/Winnipeg", 1162710000, "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Yakutat", 1162720800, "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Yellowknife", 1162713600, "MST7MDT,M3.2.0,M11.1.0"),
+];
+
+#[cfg(feature = "tzdb-antarctica")]
+pub(super) const ANTARCTICA: &[(&str, i64, &str)] = &[
+    ("Antarctica/Casey", 1678291200, "<+08>-8"),
+    ("Antarctica/Davis", 1329854400, "<+07>-7"),
+    ("Antarctica/DumontDUrville", -415497600, "<+10>-10"),
+    (
+        "Antarctica/Macquarie",
+        1286035200,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Antarctica/Mawson", 1255809600, "<+05>-5"),
+    (
+        "Antarctica/McMurdo",
+        1175349600,
+        "NZST-12NZDT,M9.5.0,M4.1.0/3",
+    ),
+    ("Antarctica/Palmer", 1471147200, "<-03>3"),
+    ("Antarctica/Rothera", 218246400, "<-03>3"),
+    (
+        "Antarctica/South_Pole",
+        1175349600,
+        "NZST-12NZDT,M9.5.0,M4.1.0/3",
+    ),
+    ("Antarctica/Syowa", -407808000, "<+03>-3"),
+    (
+        "Antarctica/Troll",
+        1108166400,
+        "<+00>0<+02>-2,M3.5.0/1,M10.5.0/3",
+    ),
+    ("Antarctica/Vostok", 1702839600, "<+05>-5"),
+];
+
+#[cfg(feature = "tzdb-arctic")]
+pub(super) const ARCTIC: &[(&str, i64, &str)] = &[(
+    "Arctic/Longyearbyen",
+    814928400,
+    "CET-1CEST,M3.5.0,M10.5.0/3",
+)];
+
+#[cfg(feature = "tzdb-asia")]
+pub(super) const ASIA: &[(&str, i64, &str)] = &[
+    ("Asia/Aden", -631162794, "<+03>-3"),
+    ("Asia/Almaty", 1709229600, "<+05>-5"),
+    ("Asia/Amman", 1666908000, "<+03>-3"),
+    ("Asia/Anadyr", 1301151600, "<+12>-12"),
+    ("Asia/Aqtau", 1080424800, "<+05>-5"),
+    ("Asia/Aqtobe", 1099170000, "<+05>-5"),
+    ("Asia/Ashgabat", 695772000, "<+05>-5"),
+    ("Asia/Ashkhabad", 695772000, "<+05>-5"),
+    ("Asia/Atyrau", 1080424800, "<+05>-5"),
+    ("Asia/Baghdad", 1191196800, "<+03>-3"),
+    ("Asia/Bahrain", 76190400, "<+03>-3"),
+    ("Asia/Baku", 1445731200, "<+04>-4"),
+    ("Asia/Bangkok", -1570084924, "<+07>-7"),
+    ("Asia/Barnaul", 1459022400, "<+07>-7"),
+    ("Asia/Beirut", 909262800, "EET-2EEST,M3.5.0/0,M10.5.0/0"),
+    ("Asia/Bishkek", 1111872600, "<+06>-6"),
+    ("Asia/Brunei", -1167636600, "<+08>-8"),
+    ("Asia/Calcutta", -764145000, "IST-5:30"),
+    ("Asia/Chita", 1459015200, "<+09>-9"),
+    ("Asia/Choibalsan", 1474642800, "<+08>-8"),
+    ("Asia/Chongqing", 684867600, "CST-8"),
+    ("Asia/Chungking", 684867600, "CST-8"),
+    ("Asia/Colombo", 1145039400, "<+0530>-5:30"),
+    ("Asia/Dacca", 1262278800, "<+06>-6"),
+    ("Asia/Damascus", 1666904400, "<+03>-3"),
+    ("Asia/Dhaka", 1262278800, "<+06>-6"),
+    ("Asia/Dili", 969120000, "<+09>-9"),
+    ("Asia/Dubai", -1577936472, "<+04>-4"),
+    ("Asia/Dushanbe", 684363600, "<+05>-5"),
+    ("Asia/Famagusta", 1509238800, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Asia/Harbin", 684867600, "CST-8"),
+    ("Asia/Ho_Chi_Minh", 171820800, "<+07>-7"),
+    ("Asia/Hong_Kong", 309292200, "HKT-8"),
+    ("Asia/Hovd", 1474646400, "<+07>-7"),
+    ("Asia/Irkutsk", 1414256400, "<+08>-8"),
+    ("Asia/Istanbul", 1473195600, "<+03>-3"),
+    ("Asia/Jakarta", -189415800, "WIB-7"),
+    ("Asia/Jayapura", -189423000, "WIT-9"),
+    ("Asia/Jerusalem", 1351378800, "IST-2IDT,M3.4.4/26,M10.5.0"),
+    ("Asia/Kabul", -788932800, "<+0430>-4:30"),
+    ("Asia/Kamchatka", 1301151600, "<+12>-12"),
+    ("Asia/Karachi", 1257012000, "PKT-5"),
+    ("Asia/Kashgar", -1325483420, "<+06>-6"),
+    ("Asia/Kathmandu", 504901800, "<+0545>-5:45"),
+    ("Asia/Katmandu", 504901800, "<+0545>-5:45"),
+    ("Asia/Khandyga", 1414252800, "<+09>-9"),
+    ("Asia/Kolkata", -764145000, "IST-5:30"),
+    ("Asia/Krasnoyarsk", 1414260000, "<+07>-7"),
+    ("Asia/Kuala_Lumpur", 378662400, "<+08>-8"),
+    ("Asia/Kuching", -767005200, "<+08>-8"),
+    ("Asia/Kuwait", -631163516, "<+03>-3"),
+    ("Asia/Macao", 309292200, "CST-8"),
+    ("Asia/Macau", 309292200, "CST-8"),
+    ("Asia/Magadan", 1461427200, "<+11>-11"),
+    ("Asia/Makassar", -766054800, "WITA-8"),
+    ("Asia/Manila", 649177200, "PST-8"),
+    ("Asia/Muscat", -1577937264, "<+04>-4"),
+    ("Asia/Nicosia", 891133200, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Asia/Novokuznetsk", 1301169600, "<+07>-7"),
+    ("Asia/Novosibirsk", 1469304000, "<+07>-7"),
+    ("Asia/Omsk", 1414263600, "<+06>-6"),
+    ("Asia/Oral", 1080424800, "<+05>-5"),
+    ("Asia/Phnom_Penh", -767869200, "<+07>-7"),
+    ("Asia/Pontianak", 567964800, "WIB-7"),
+    ("Asia/Pyongyang", 1525446000, "KST-9"),
+    ("Asia/Qatar", 76190400, "<+03>-3"),
+    ("Asia/Qostanay", 1709229600, "<+05>-5"),
+    ("Asia/Qyzylorda", 1545328800, "<+05>-5"),
+    ("Asia/Rangoon", -778410000, "<+0630>-6:30"),
+    ("Asia/Riyadh", -719636812, "<+03>-3"),
+    ("Asia/Saigon", 171820800, "<+07>-7"),
+    ("Asia/Sakhalin", 1459008000, "<+11>-11"),
+    ("Asia/Samarkand", 686091600, "<+05>-5"),
+    ("Asia/Seoul", 592333200, "KST-9"),
+    ("Asia/Shanghai", 684867600, "CST-8"),
+    ("Asia/Singapore", 378662400, "<+08>-8"),
+    ("Asia/Srednekolymsk", 1414245600, "<+11>-11"),
+    ("Asia/Taipei", 307551600, "CST-8"),
+    ("Asia/Tashkent", 686091600, "<+05>-5"),
+    ("Asia/Tbilisi", 1111878000, "<+04>-4"),
+    ("Asia/Tehran", 1663788600, "<+0330>-3:30"),
+    ("Asia/Tel_Aviv", 1351378800, "IST-2IDT,M3.4.4/26,M10.5.0"),
+    ("Asia/Thimbu", 560025000, "<+06>-6"),
+    ("Asia/Thimphu", 560025000, "<+06>-6"),
+    ("Asia/Tokyo", -577962000, "JST-9"),
+    ("Asia/Tomsk", 1464465600, "<+07>-7"),
+    ("Asia/Ujung_Pandang", -766054800, "WITA-8"),
+    ("Asia/Ulaanbaatar", 1474642800, "<+08>-8"),
+    ("Asia/Ulan_Bator", 1474642800, "<+08>-8"),
+    ("Asia/Urumqi", -1325483420, "<+06>-6"),
+    ("Asia/Ust-Nera", 1414249200, "<+10>-10"),
+    ("Asia/Vientiane", -464428800, "<+07>-7"),
+    ("Asia/Vladivostok", 1414249200, "<+10>-10"),
+    ("Asia/Yakutsk", 1414252800, "<+09>-9"),
+    ("Asia/Yangon", -778410000, "<+0630>-6:30"),
+    ("Asia/Yekaterinburg", 1414267200, "<+05>-5"),
+    ("Asia/Yerevan", 1319925600, "<+04>-4"),
+];
+
+#[cfg(feature = "tzdb-atlantic")]
+pub(super) const ATLANTIC: &[(&str, i64, &str)] = &[
+    (
+        "Atlantic/Azores",
+        814928400,
+        "<-01>1<+00>,M3.5.0/0,M10.5.0/1",
+    ),
+    ("Atlantic/Bermuda", 1162702800, "AST4ADT,M3.2.0,M11.1.0"),
+    ("Atlantic/Canary", 814928400, "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/Cape_Verde", 186120000, "<-01>1"),
+    ("Atlantic/Faeroe", 814928400, "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/Faroe", 814928400, "WET0WEST,M3.5.0/1,M10.5.0"),
+    (
+        "Atlantic/Jan_Mayen",
+        814928400,
+        "CET-1CEST,M3.5.0,M10.5.0/3",
+    ),
+    ("Atlantic/Madeira", 814928400, "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/Reykjavik", -54770400, "GMT0"),
+    ("Atlantic/South_Georgia", -2524512832, "<-02>2"),
+    ("Atlantic/St_Helena", -599614632, "GMT0"),
+    ("Atlantic/Stanley", 1283666400, "<-03>3"),
+];
+
+#[cfg(feature = "tzdb-australia")]
+pub(super) const AUSTRALIA: &[(&str, i64, &str)] = &[
+    ("Australia/ACT", 1193500800, "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    (
+        "Australia/Adelaide",
+        1193502600,
+        "ACST-9:30ACDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Australia/Brisbane", 699379200, "AEST-10"),
+    (
+        "Australia/Broken_Hill",
+        1193502600,
+        "ACST-9:30ACDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/Canberra",
+        1193500800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/Currie",
+        1175356800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Australia/Darwin", -813223800, "ACST-9:30"),
+    ("Australia/Eucla", 1238260500, "<+0845>-8:45"),
+    (
+        "Australia/Hobart",
+        1175356800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/LHI",
+        1193499000,
+        "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+    ),
+    ("Australia/Lindeman", 762883200, "AEST-10"),
+    (
+        "Australia/Lord_Howe",
+        1193499000,
+        "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+    ),
+    (
+        "Australia/Melbourne",
+        1193500800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Australia/NSW", 1193500800, "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Australia/North", -813223800, "ACST-9:30"),
+    ("Australia/Perth", 1238263200, "AWST-8"),
+    ("Australia/Queensland", 699379200, "AEST-10"),
+    (
+        "Australia/South",
+        1193502600,
+        "ACST-9:30ACDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/Sydney",
+        1193500800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/Tasmania",
+        1175356800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    (
+        "Australia/Victoria",
+        1193500800,
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+    ),
+    ("Australia/West", 1238263200, "AWST-8"),
+    (
+        "Australia/Yancowinna",
+        1193502600,
+        "ACST-9:30ACDT,M10.1.0,M4.1.0/3",
+    ),
+];
+
+pub(super) const ETC: &[(&str, i64, &str)] = &[
+    ("Etc/GMT", i64::MIN, "GMT0"),
+    ("Etc/GMT+0", i64::MIN, "GMT0"),
+    ("Etc/GMT+1", i64::MIN, "<-01>1"),
+    ("Etc/GMT+10", i64::MIN, "<-10>10"),
+    ("Etc/GMT+11", i64::MIN, "<-11>11"),
+    ("Etc/GMT+12", i64::MIN, "<-12>12"),
+    ("Etc/GMT+2", i64::MIN, "<-02>2"),
+    ("Etc/GMT+3", i64::MIN, "<-03>3"),
+    ("Etc/GMT+4", i64::MIN, "<-04>4"),
+    ("Etc/GMT+5", i64::MIN, "<-05>5"),
+    ("Etc/GMT+6", i64::MIN, "<-06>6"),
+    ("Etc/GMT+7", i64::MIN, "<-07>7"),
+    ("Etc/GMT+8", i64::MIN, "<-08>8"),
+    ("Etc/GMT+9", i64::MIN, "<-09>9"),
+    ("Etc/GMT-0", i64::MIN, "GMT0"),
+    ("Etc/GMT-1", i64::MIN, "<+01>-1"),
+    ("Etc/GMT-10", i64::MIN, "<+10>-10"),
+    ("Etc/GMT-11", i64::MIN, "<+11>-11"),
+    ("Etc/GMT-12", i64::MIN, "<+12>-12"),
+    ("Etc/GMT-13", i64::MIN, "<+13>-13"),
+    ("Etc/GMT-14", i64::MIN, "<+14>-14"),
+    ("Etc/GMT-2", i64::MIN, "<+02>-2"),
+    ("Etc/GMT-3", i64::MIN, "<+03>-3"),
+    ("Etc/GMT-4", i64::MIN, "<+04>-4"),
+    ("Etc/GMT-5", i64::MIN, "<+05>-5"),
+    ("Etc/GMT-6", i64::MIN, "<+06>-6"),
+    ("Etc/GMT-7", i64::MIN, "<+07>-7"),
+    ("Etc/GMT-8", i64::MIN, "<+08>-8"),
+    ("Etc/GMT-9", i64::MIN, "<+09>-9"),
+    ("Etc/GMT0", i64::MIN, "GMT0"),
+    ("Etc/Greenwich", i64::MIN, "GMT0"),
+    ("Etc/UCT", i64::MIN, "UTC0"),
+    ("Etc/UTC", i64::MIN, "UTC0"),
+    ("Etc/Universal", i64::MIN, "UTC0"),
+    ("Etc/Zulu", i64::MIN, "UTC0"),
+    ("UTC", i64::MIN, "UTC0"),
+];
+
+#[cfg(feature = "tzdb-europe")]
+pub(super) const EUROPE: &[(&str, i64, &str)] = &[
+    ("Europe/Amsterdam", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Andorra", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Astrakhan", 1459033200, "<+04>-4"),
+    ("Europe/Athens", 814928400, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Belfast", 814928400, "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Belgrade", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Berlin", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Bratislava", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Brussels", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    (
+        "Europe/Bucharest",
+        846378000,
+        "EET-2EEST,M3.5.0/3,M10.5.0/4",
+    ),
+    ("Europe/Budapest", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Busingen", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Chisinau", 846374400, "EET-2EEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Copenhagen", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Dublin", 814928400, "IST-1GMT0,M10.5.0,M3.5.0/1"),
+    ("Europe/Gibraltar", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Guernsey", 814928400, "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Helsinki", 814928400, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Isle_of_Man", 814928400, "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Istanbul", 1473195600, "<+03>-3"),
+    ("Europe/Jersey", 814928400, "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Kaliningrad", 1414278000, "EET-2"),
+    ("Europe/Kiev", 828234000, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Kirov", 1414274400, "MSK-3"),
+    ("Europe/Kyiv", 828234000, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Lisbon", 828234000, "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Europe/Ljubljana", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/London", 814928400, "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Luxembourg", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Madrid", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Malta", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    (
+        "Europe/Mariehamn",
+        814928400,
+        "EET-2EEST,M3.5.0/3,M10.5.0/4",
+    ),
+    ("Europe/Minsk", 1301184000, "<+03>-3"),
+    ("Europe/Monaco", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Moscow", 1414274400, "MSK-3"),
+    ("Europe/Nicosia", 891133200, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Oslo", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Paris", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Podgorica", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Prague", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Riga", 972781200, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Rome", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Samara", 1301180400, "<+04>-4"),
+    ("Europe/San_Marino", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Sarajevo", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Saratov", 1480806000, "<+04>-4"),
+    ("Europe/Simferopol", 1414274400, "MSK-3"),
+    ("Europe/Skopje", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Sofia", 846378000, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Stockholm", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Tallinn", 1004230800, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Tirane", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Tiraspol", 846374400, "EET-2EEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Ulyanovsk", 1459033200, "<+04>-4"),
+    ("Europe/Uzhgorod", 828234000, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Vaduz", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Vatican", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Vienna", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Vilnius", 1035680400, "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Volgograd", 1609020000, "MSK-3"),
+    ("Europe/Warsaw", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Zagreb", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+    (
+        "Europe/Zaporozhye",
+        828234000,
+        "EET-2EEST,M3.5.0/3,M10.5.0/4",
+    ),
+    ("Europe/Zurich", 814928400, "CET-1CEST,M3.5.0,M10.5.0/3"),
+];
+
+#[cfg(feature = "tzdb-indian")]
+pub(super) const INDIAN: &[(&str, i64, &str)] = &[
+    ("Indian/Antananarivo", -492062400, "EAT-3"),
+    ("Indian/Chagos", 820436400, "<+06>-6"),
+    ("Indian/Christmas", -2364102172, "<+07>-7"),
+    ("Indian/Cocos", -2209012060, "<+0630>-6:30"),
+    ("Indian/Comoro", -1846291984, "EAT-3"),
+    ("Indian/Kerguelen", -631152000, "<+05>-5"),
+    ("Indian/Mahe", -1988163708, "<+04>-4"),
+    ("Indian/Maldives", -315636840, "<+05>-5"),
+    ("Indian/Mauritius", 1238274000, "<+04>-4"),
+    ("Indian/Mayotte", -1846292456, "EAT-3"),
+    ("Indian/Reunion", -1848886912, "<+04>-4"),
+];
+
+#[cfg(feature = "tzdb-pacific")]
+pub(super) const PACIFIC: &[(&str, i64, &str)] = &[
+    ("Pacific/Apia", 1617458400, "<+13>-13"),
+    (
+        "Pacific/Auckland",
+        1175349600,
+        "NZST-12NZDT,M9.5.0,M4.1.0/3",
+    ),
+    ("Pacific/Bougainville", 1419696000, "<+11>-11"),
+    (
+        "Pacific/Chatham",
+        1175349600,
+        "<+1245>-12:45<+1345>,M9.5.0/2:45,M4.1.0/3:45",
+    ),
+    ("Pacific/Chuuk", -770634000, "<+10>-10"),
+    (
+        "Pacific/Easter",
+        1662868800,
+        "<-06>6<-05>,M9.1.6/22,M4.1.6/22",
+    ),
+    ("Pacific/Efate", 727790400, "<+11>-11"),
+    ("Pacific/Enderbury", 788871600, "<+13>-13"),
+    ("Pacific/Fakaofo", 1325242800, "<+13>-13"),
+    ("Pacific/Fiji", 1610805600, "<+12>-12"),
+    ("Pacific/Funafuti", -2177495812, "<+12>-12"),
+    ("Pacific/Galapagos", 728888400, "<-06>6"),
+    ("Pacific/Gambier", -1806678012, "<-09>9"),
+    ("Pacific/Guadalcanal", -1806748788, "<+11>-11"),
+    ("Pacific/Guam", 977493600, "ChST-10"),
+    ("Pacific/Honolulu", -712150200, "HST10"),
+    ("Pacific/Johnston", -712150200, "HST10"),
+    ("Pacific/Kanton", 788871600, "<+13>-13"),
+    ("Pacific/Kiritimati", 788868000, "<+14>-14"),
+    ("Pacific/Kosrae", 915105600, "<+11>-11"),
+    ("Pacific/Kwajalein", 745934400, "<+12>-12"),
+    ("Pacific/Majuro", -7988400, "<+12>-12"),
+    ("Pacific/Marquesas", -1806676920, "<-0930>9:30"),
+    ("Pacific/Midway", -420645600, "SST11"),
+    ("Pacific/Nauru", 287418600, "<+12>-12"),
+    ("Pacific/Niue", -173623200, "<-11>11"),
+    (
+        "Pacific/Norfolk",
+        1554562800,
+        "<+11>-11<+12>,M10.1.0,M4.1.0/3",
+    ),
+    ("Pacific/Noumea", 857228400, "<+11>-11"),
+    ("Pacific/Pago_Pago", -1861879032, "SST11"),
+    ("Pacific/Palau", -2177485076, "<+09>-9"),
+    ("Pacific/Pitcairn", 893665800, "<-08>8"),
+    ("Pacific/Pohnpei", -770634000, "<+11>-11"),
+    ("Pacific/Ponape", -1806748788, "<+11>-11"),
+    ("Pacific/Port_Moresby", -2366790512, "<+10>-10"),
+    ("Pacific/Rarotonga", 667992600, "<-10>10"),
+    ("Pacific/Saipan", 977493600, "ChST-10"),
+    ("Pacific/Samoa", -1861879032, "SST11"),
+    ("Pacific/Tahiti", -1806674504, "<-10>10"),
+    ("Pacific/Tarawa", -2177494324, "<+12>-12"),
+    ("Pacific/Tongatapu", 1484398800, "<+13>-13"),
+    ("Pacific/Truk", -2366790512, "<+10>-10"),
+    ("Pacific/Wake", -2177492788, "<+12>-12"),
+    ("Pacific/Wallis", -2177496920, "<+12>-12"),
+    ("Pacific/Yap", -2366790512, "<+10>-10"),
+];
@@ -0,0 +1,435 @@
+//! TZif time zone files (RFC 8536).
+//!
+//! A [`Tzif`] borrows or owns the raw file and looks up transitions in place,
+//! so a single zone file can be embedded with `include_bytes!` and used
+//! without allocation. With the `std` feature zones can also be loaded from
+//! the system zoneinfo database.
+
+use core::fmt;
+
+use crate::{Error, LocalTimeType, PosixTz, TimeZone, UtcOffset};
+
+const HEADER_LEN: usize = 44;
+
+/// An error returned when a TZif file could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TzifError {
+    /// The data does not start with a TZif header.
+    InvalidHeader,
+    /// The file has an unknown version byte.
+    UnsupportedVersion(u8),
+    /// The data ends before the end of the file.
+    UnexpectedEnd,
+    /// The file is malformed.
+    InvalidData,
+}
+
+impl fmt::Display for TzifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TzifError::InvalidHeader => f.write_str("invalid tzif header"),
+            TzifError::UnsupportedVersion(version) => {
+                write!(f, "unsupported tzif version {:#04x}", version)
+            }
+            TzifError::UnexpectedEnd => f.write_str("unexpected end of tzif data"),
+            TzifError::InvalidData => f.write_str("invalid tzif data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TzifError {}
+
+/// The counts from a TZif header.
+struct Header {
+    version: u8,
+    isut_count: usize,
+    isstd_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+/// Positions of the parts of a TZif data block that are used for lookups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Layout {
+    version: u8,
+    time_size: usize,
+    times: usize,
+    time_count: usize,
+    indices: usize,
+    types: usize,
+    type_count: usize,
+    chars: usize,
+    char_count: usize,
+    footer: usize,
+    footer_len: usize,
+}
+
+fn be_bytes<const N: usize>(data: &[u8], pos: usize) -> [u8; N] {
+    data[pos..pos + N].try_into().expect("validated length")
+}
+
+fn read_header(data: &[u8], pos: usize) -> Result<Header, TzifError> {
+    let header = data
+        .get(pos..)
+        .and_then(|data| data.get(..HEADER_LEN))
+        .ok_or(TzifError::UnexpectedEnd)?;
+    if !header.starts_with(b"TZif") {
+        return Err(TzifError::InvalidHeader);
+    }
+
+    let version = match header[4] {
+        0 => 1,
+        version @ b'2'..=b'4' => version - b'0',
+        version => return Err(TzifError::UnsupportedVersion(version)),
+    };
+    let count = |index: usize| u32::from_be_bytes(be_bytes(header, 20 + 4 * index)) as usize;
+
+    Ok(Header {
+        version,
+        isut_count: count(0),
+        isstd_count: count(1),
+        leap_count: count(2),
+        time_count: count(3),
+        type_count: count(4),
+        char_count: count(5),
+    })
+}
+
+/// Reads the header and data block starting at `pos`, returning the layout
+/// and the position after the block.
+fn read_block(data: &[u8], pos: usize, time_size: usize) -> Result<(Layout, usize), TzifError> {
+    let header = read_header(data, pos)?;
+
+    let mut end = pos + HEADER_LEN;
+    let mut take = |len: Option<usize>| {
+        let start = end;
+        end = len
+            .and_then(|len| end.checked_add(len))
+            .ok_or(TzifError::UnexpectedEnd)?;
+        Ok(start)
+    };
+
+    let times = take(header.time_count.checked_mul(time_size))?;
+    let indices = take(Some(header.time_count))?;
+    let types = take(header.type_count.checked_mul(6))?;
+    let chars = take(Some(header.char_count))?;
+    take(header.leap_count.checked_mul(time_size + 4))?;
+    take(Some(header.isstd_count))?;
+    take(Some(header.isut_count))?;
+
+    if end > data.len() {
+        return Err(TzifError::UnexpectedEnd);
+    }
+    if header.type_count == 0
+        || header.char_count == 0
+        || (header.isut_count != 0 && header.isut_count != header.type_count)
+        || (header.isstd_count != 0 && header.isstd_count != header.type_count)
+    {
+        return Err(TzifError::InvalidData);
+    }
+
+    let layout = Layout {
+        version: header.version,
+        time_size,
+        times,
+        time_count: header.time_count,
+        indices,
+        types,
+        type_count: header.type_count,
+        chars,
+        char_count: header.char_count,
+        footer: end,
+        footer_len: 0,
+    };
+    Ok((layout, end))
+}
+
+impl Layout {
+    fn time(&self, data: &[u8], index: usize) -> i64 {
+        let pos = self.times + index * self.time_size;
+        match self.time_size {
+            4 => i32::from_be_bytes(be_bytes(data, pos)) as i64,
+            _ => i64::from_be_bytes(be_bytes(data, pos)),
+        }
+    }
+
+    fn local_time_type<'a>(&self, data: &'a [u8], index: usize) -> LocalTimeType<'a> {
+        let pos = self.types + index * 6;
+        let chars = &data[self.chars..self.chars + self.char_count];
+        let abbreviation = &chars[data[pos + 5] as usize..];
+        let len = abbreviation
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(abbreviation.len());
+
+        LocalTimeType {
+            offset: i32::from_be_bytes(be_bytes(data, pos)),
+            is_dst: data[pos + 4] == 1,
+            abbreviation: core::str::from_utf8(&abbreviation[..len]).unwrap_or_default(),
+        }
+    }
+
+    fn validate(&self, data: &[u8]) -> Result<(), TzifError> {
+        let times_sorted =
+            (1..self.time_count).all(|i| self.time(data, i - 1) < self.time(data, i));
+        let indices_valid = data[self.indices..self.indices + self.time_count]
+            .iter()
+            .all(|&index| (index as usize) < self.type_count);
+        let types_valid = (0..self.type_count).all(|i| {
+            let pos = self.types + i * 6;
+            i32::from_be_bytes(be_bytes(data, pos)) != i32::MIN
+                && data[pos + 4] <= 1
+                && (data[pos + 5] as usize) < self.char_count
+        });
+        let chars_valid = data[self.chars..self.chars + self.char_count].is_ascii()
+            && data[self.footer..self.footer + self.footer_len].is_ascii();
+
+        if times_sorted && indices_valid && types_valid && chars_valid {
+            Ok(())
+        } else {
+            Err(TzifError::InvalidData)
+        }
+    }
+}
+
+/// A time zone read from a TZif file, as found in `/usr/share/zoneinfo`.
+///
+/// Local time before the first transition uses the first local time type.
+/// After the last transition the TZ string in the footer is used if there is
+/// one, otherwise the type of the last transition. Leap second records are
+/// ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tzif<D> {
+    data: D,
+    layout: Layout,
+}
+
+impl<D: AsRef<[u8]>> Tzif<D> {
+    /// Parses a TZif file of version 1 to 4.
+    ///
+    /// Only the 64 bit data of version 2 and later files is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TzifError::InvalidHeader`] or
+    /// [`TzifError::UnsupportedVersion`] if `data` is not a TZif file,
+    /// [`TzifError::UnexpectedEnd`] if it is truncated and
+    /// [`TzifError::InvalidData`] if it is malformed, including the TZ string
+    /// in the footer.
+    pub fn parse(data: D) -> Result<Tzif<D>, TzifError> {
+        let bytes = data.as_ref();
+
+        let (mut layout, end) = read_block(bytes, 0, 4)?;
+        if layout.version >= 2 {
+            let (block, end) = read_block(bytes, end, 8)?;
+            match bytes.get(end) {
+                Some(b'\n') => {}
+                Some(_) => return Err(TzifError::InvalidData),
+                None => return Err(TzifError::UnexpectedEnd),
+            }
+            let footer_len = bytes[end + 1..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .ok_or(TzifError::UnexpectedEnd)?;
+
+            layout = Layout {
+                footer: end + 1,
+                footer_len,
+                ..block
+            };
+        }
+
+        layout.validate(bytes)?;
+        let zone = Tzif { data, layout };
+        if !zone.footer().is_empty() && zone.rule().is_none() {
+            return Err(TzifError::InvalidData);
+        }
+
+        Ok(zone)
+    }
+
+    /// Returns the TZ string from the footer of version 2 and later files,
+    /// empty if there is none.
+    pub fn footer(&self) -> &str {
+        let footer = &self.data.as_ref()[self.layout.footer..][..self.layout.footer_len];
+        core::str::from_utf8(footer).unwrap_or_default()
+    }
+
+    /// Returns the rule for times after the last transition from the footer,
+    /// if there is one.
+    pub fn rule(&self) -> Option<PosixTz<'_>> {
+        PosixTz::parse(self.footer()).ok()
+    }
+
+    /// Returns the local time type in effect at the given Unix time.
+    pub fn local_time_type(&self, seconds: i64) -> LocalTimeType<'_> {
+        let data = self.data.as_ref();
+        let layout = &self.layout;
+
+        let (mut low, mut high) = (0, layout.time_count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if layout.time(data, mid) <= seconds {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == layout.time_count {
+            if let Some(rule) = self.rule() {
+                return rule.local_time_type(seconds);
+            }
+        }
+
+        let index = match low {
+            0 => 0,
+            _ => data[layout.indices + low - 1] as usize,
+        };
+        layout.local_time_type(data, index)
+    }
+}
+
+impl<D: AsRef<[u8]>> TimeZone for Tzif<D> {
+    /// Returns the offset of the local time type in effect, truncated to whole
+    /// minutes.
+    fn utc_offset(&self, seconds: i64) -> Result<UtcOffset, Error> {
+        UtcOffset::from_seconds(self.local_time_type(seconds).offset_seconds())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Tzif<std::vec::Vec<u8>> {
+    /// Loads a zone such as `Europe/Berlin` from the zoneinfo directory given
+    /// by the `TZDIR` environment variable, `/usr/share/zoneinfo` by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`std::io::ErrorKind::InvalidInput`] if `name`
+    /// is not a relative path within the directory, and any error
+    /// [`Tzif::read`] returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rfc3339::{format_unix_zone, Tzif};
+    ///
+    /// let berlin = Tzif::load("Europe/Berlin").unwrap();
+    /// let timestamp = format_unix_zone(1445470140, 0, &berlin).unwrap();
+    /// assert_eq!(timestamp, "2015-10-22T01:29:00.000000+02:00");
+    /// assert_eq!(berlin.local_time_type(1445470140).abbreviation(), "CEST");
+    /// ```
+    pub fn load(name: &str) -> std::io::Result<Self> {
+        use std::io;
+        use std::path::PathBuf;
+
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|part| part == "..") {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid time zone name",
+            ));
+        }
+
+        let dir = std::env::var_os("TZDIR").unwrap_or_else(|| "/usr/share/zoneinfo".into());
+        Tzif::read(PathBuf::from(dir).join(name))
+    }
+
+    /// Reads a TZif file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reading the file, and an error of kind
+    /// [`std::io::ErrorKind::InvalidData`] wrapping a [`TzifError`] if it
+    /// cannot be parsed.
+    pub fn read<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let data = std::fs::read(path)?;
+        Tzif::parse(data).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DateTime, Precision};
+
+    /// Version 1 file with the 2023 CET/CEST transitions.
+    const CET: [u8; 75] = [
+        b'T', b'Z', b'i', b'f', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // magic
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 9, // counts
+        0x64, 0x1f, 0x99, 0x10, 0x65, 0x3d, 0xae, 0x90, // transition times
+        1, 0, // transition types
+        0, 0, 0x0e, 0x10, 0, 0, 0, 0, 0x1c, 0x20, 1, 4, // local time types
+        b'C', b'E', b'T', 0, b'C', b'E', b'S', b'T', 0, // abbreviations
+    ];
+
+    #[test]
+    fn test_lookup() {
+        let zone = Tzif::parse(&CET[..]).unwrap();
+        assert_eq!(zone.footer(), "");
+
+        let cest = zone.local_time_type(1679792400);
+        assert_eq!(
+            (cest.offset_seconds(), cest.is_dst(), cest.abbreviation()),
+            (7200, true, "CEST")
+        );
+        assert_eq!(zone.local_time_type(1679792399).abbreviation(), "CET");
+        assert_eq!(zone.local_time_type(1698541200).abbreviation(), "CET");
+        assert_eq!(zone.local_time_type(i64::MIN).abbreviation(), "CET");
+
+        let datetime = DateTime::from_unix_zone(1679792400, 0, &zone).unwrap();
+        assert_eq!(
+            datetime.format(Precision::Seconds).unwrap(),
+            "2023-03-26T03:00:00+02:00"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Tzif::parse(&CET[..74]), Err(TzifError::UnexpectedEnd));
+        assert_eq!(Tzif::parse(&CET[1..]), Err(TzifError::InvalidHeader));
+
+        let mut data = CET;
+        data[4] = b'5';
+        assert_eq!(Tzif::parse(data), Err(TzifError::UnsupportedVersion(b'5')));
+
+        let mut data = CET;
+        data[52] = 2;
+        assert_eq!(Tzif::parse(data), Err(TzifError::InvalidData));
+    }
+
+    #[test]
+    fn test_footer() {
+        let data = include_bytes!("../tests/data/America_Los_Angeles.tzif");
+        let zone = Tzif::parse(&data[..]).unwrap();
+        assert_eq!(zone.footer(), "PST8PDT,M3.2.0,M11.1.0");
+        assert_eq!(zone.local_time_type(1445470140).abbreviation(), "PDT");
+        assert_eq!(zone.local_time_type(1451606400).abbreviation(), "PST");
+        // After the last transition in 2037, from the footer.
+        assert_eq!(zone.local_time_type(4102444800).abbreviation(), "PST");
+        assert_eq!(zone.local_time_type(4118083200).abbreviation(), "PDT");
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod std_tests {
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/America_Los_Angeles.tzif"
+        );
+        let zone = Tzif::read(path).unwrap();
+        assert_eq!(zone.footer(), "PST8PDT,M3.2.0,M11.1.0");
+    }
+
+    #[test]
+    fn test_load() {
+        let err = Tzif::load("../../etc/passwd").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
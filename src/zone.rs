@@ -0,0 +1,94 @@
+//! Time zones with offsets that change over time.
+
+use crate::{DateTime, Error, Precision, Timestamp, UtcOffset};
+
+/// A time zone, giving the offset from UTC of local time at each instant.
+pub trait TimeZone {
+    /// Returns the offset from UTC in effect at the given Unix time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOffset`] if the offset cannot be represented
+    /// as a [`UtcOffset`].
+    fn utc_offset(&self, seconds: i64) -> Result<UtcOffset, Error>;
+}
+
+/// A local time type of a time zone, such as CEST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalTimeType<'a> {
+    pub(crate) offset: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) abbreviation: &'a str,
+}
+
+impl<'a> LocalTimeType<'a> {
+    /// Returns the offset from UTC in seconds east of UTC.
+    pub fn offset_seconds(&self) -> i32 {
+        self.offset
+    }
+
+    /// Returns true if this is daylight saving time.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Returns the abbreviation, such as `CEST`.
+    pub fn abbreviation(&self) -> &'a str {
+        self.abbreviation
+    }
+}
+
+/// A fixed offset is a time zone that never changes.
+impl TimeZone for UtcOffset {
+    fn utc_offset(&self, _seconds: i64) -> Result<UtcOffset, Error> {
+        Ok(*self)
+    }
+}
+
+impl<Z: TimeZone + ?Sized> TimeZone for &Z {
+    fn utc_offset(&self, seconds: i64) -> Result<UtcOffset, Error> {
+        (**self).utc_offset(seconds)
+    }
+}
+
+impl DateTime {
+    /// Creates a date and time in local time of the given time zone from a
+    /// Unix timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`TimeZone::utc_offset`] and
+    /// [`DateTime::from_unix_offset`] return.
+    pub fn from_unix_zone<Z: TimeZone + ?Sized>(
+        seconds: i64,
+        nanos: u32,
+        zone: &Z,
+    ) -> Result<DateTime, Error> {
+        DateTime::from_unix_offset(seconds, nanos, zone.utc_offset(seconds)?)
+    }
+}
+
+/// Converts a signed Unix timestamp with nanosecond resolution into an RFC3339
+/// formatted date-time string in local time of the given time zone, with the
+/// default six fractional digits.
+///
+/// # Errors
+///
+/// Returns any error [`DateTime::from_unix_zone`] returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfc3339::{format_unix_zone, UtcOffset};
+///
+/// let pdt = UtcOffset::from_minutes(-7 * 60).unwrap();
+/// let timestamp = format_unix_zone(1445470140, 0, &pdt).unwrap();
+/// assert_eq!(timestamp, "2015-10-21T16:29:00.000000-07:00");
+/// ```
+pub fn format_unix_zone<Z: TimeZone + ?Sized>(
+    seconds: i64,
+    nanos: u32,
+    zone: &Z,
+) -> Result<Timestamp, Error> {
+    DateTime::from_unix_zone(seconds, nanos, zone)?.format(Precision::default())
+}